@@ -115,6 +115,24 @@ where
     bench(b, &v, f, n);
 }
 
+// With `std`, 64-bit `sqrt` truncates `f64::sqrt` and corrects it by one;
+// without, it seeds Newton's method from a table of the leading bits.  Other
+// roots are seeded from integer tables either way.  Rough ns/iter on an x86
+// VM, comparing `std` with `no_std`:
+//
+//     u64 sqrt_rand     9k  15k
+//     u64 sqrt_small    7k   7k
+//     i64 sqrt_rand     9k  15k
+//     u32 sqrt_rand     9k  10k
+//
+// and comparing the `f64` seeds used with `std` before, the power-of-two
+// seeds used without it, and the integer tables:
+//
+//     u32 fifth_root_rand   91k  42k  28k
+//     u32 fourth_root_rand  91k  40k  26k
+//     u64 fifth_root_rand   44k  59k  32k
+//     u64 fourth_root_rand  45k  68k  45k
+//     u64 cbrt_rand         39k  22k  20k
 macro_rules! bench_roots {
     ($($T:ident),*) => {$(
         mod $T {
@@ -160,6 +178,16 @@ macro_rules! bench_roots {
             fn fifth_root_small(b: &mut Bencher) {
                 crate::bench_small(b, |x: &$T| x.nth_root(5), 5);
             }
+
+            #[bench]
+            fn seventh_root_rand(b: &mut Bencher) {
+                crate::bench_rand(b, |x: &$T| x.nth_root(7), 7);
+            }
+
+            #[bench]
+            fn seventh_root_small(b: &mut Bencher) {
+                crate::bench_small(b, |x: &$T| x.nth_root(7), 7);
+            }
         }
     )*}
 }
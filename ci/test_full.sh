@@ -28,6 +28,7 @@ if ! check_version $MSRV ; then
 fi
 
 FEATURES=()
+check_version 1.36 && FEATURES+=(alloc)
 echo "Testing supported features: ${FEATURES[*]}"
 
 cargo generate-lockfile
@@ -0,0 +1,141 @@
+use crate::factorial_valuation;
+use crate::primes::{factorize, mul_mod, pow_mod};
+
+/// Calculate the binomial coefficient `C(n, k)` modulo `m`.
+///
+/// This never computes `C(n, k)` itself, so `n` can be as large as
+/// `u64::MAX`.  The modulus is factored, and the residue modulo each prime
+/// power `pᵉ` is combined with the Chinese remainder theorem:
+///
+/// - modulo a prime `p`, by Lucas' theorem, which takes `O(min(k, p) log n)`
+///   steps;
+/// - modulo a higher prime power, by Granville's generalization, which takes
+///   `O(min(n, pᵉ) log n)` steps.
+///
+/// So this is fast for any `n` when `m` is squarefree or its repeated prime
+/// factors are small, and for any `m` when `n` is small.
+///
+/// # Panics
+///
+/// Panics if `m` is zero.
+///
+/// # Examples
+///
+/// ```
+/// use num_integer::binomial_mod;
+///
+/// let n = 1_000_000_000_000_000_000;
+/// assert_eq!(binomial_mod(n, 2, 1_000_000_007), 1176);
+/// assert_eq!(binomial_mod(n, 3_000_000_041, 1_000_000_007), 796_877_352);
+/// assert_eq!(binomial_mod(n, 12345, 59_049), 26_973); // 3¹⁰
+///
+/// // 1_000_000_007 · 998_244_353
+/// assert_eq!(binomial_mod(n, 3_000_000_041, 998_244_359_987_710_471), 791_121_117_334_725_129);
+///
+/// assert_eq!(binomial_mod(10, 3, 1000), 120);
+/// assert_eq!(binomial_mod(3, 10, 7), 0);
+/// ```
+pub fn binomial_mod(n: u64, k: u64, m: u64) -> u64 {
+    assert!(m > 0, "the modulus must be positive");
+    if k > n {
+        return 0;
+    }
+    let k = core::cmp::min(k, n - k);
+
+    // `x` is the answer modulo `modulus`, the product of the prime powers
+    // so far, which divides `m`.
+    let mut x = 0;
+    let mut modulus = 1;
+    for &(p, e) in factorize(m).iter() {
+        let q = p.pow(e);
+        let r = if e == 1 {
+            binomial_mod_prime(n, k, p)
+        } else {
+            binomial_mod_prime_power(n, k, p, e, q)
+        };
+
+        // Find `x + modulus·t ≡ r (mod q)`.
+        let inverse = pow_mod(modulus % q, totient(p, q) - 1, q);
+        let y = x % q;
+        let diff = if r >= y { r - y } else { r + (q - y) };
+        let t = mul_mod(diff, inverse, q);
+        x += modulus * t;
+        modulus *= q;
+    }
+    x
+}
+
+/// Returns `φ(q)` for the prime power `q` of `p`.
+#[inline]
+fn totient(p: u64, q: u64) -> u64 {
+    q / p * (p - 1)
+}
+
+/// Calculate `C(n, k) mod p` for a prime `p` by Lucas' theorem, as the
+/// product of the binomial coefficients of the base `p` digits.
+fn binomial_mod_prime(mut n: u64, mut k: u64, p: u64) -> u64 {
+    let mut r = 1 % p;
+    while k > 0 {
+        let (a, b) = (n % p, k % p);
+        if b > a {
+            return 0;
+        }
+        r = mul_mod(r, small_binomial_mod(a, b, p), p);
+        n /= p;
+        k /= p;
+    }
+    r
+}
+
+/// Calculate `C(a, b) mod p` for a prime `p` and `b ≤ a < p`.
+fn small_binomial_mod(a: u64, b: u64, p: u64) -> u64 {
+    let b = core::cmp::min(b, a - b);
+    let mut num = 1;
+    let mut den = 1;
+    for i in 0..b {
+        num = mul_mod(num, a - i, p);
+        den = mul_mod(den, i + 1, p);
+    }
+    // None of the factors are multiples of `p`, so `den` is invertible.
+    mul_mod(num, pow_mod(den, p - 2, p), p)
+}
+
+/// Calculate `C(n, k) mod q` for a prime power `q = pᵉ` with `e ≥ 2`.
+///
+/// This splits each factorial into the power of `p` that divides it and the
+/// product of the rest, which is a unit modulo `q`.
+fn binomial_mod_prime_power(n: u64, k: u64, p: u64, e: u32, q: u64) -> u64 {
+    let c = factorial_valuation(n, p) - factorial_valuation(k, p) - factorial_valuation(n - k, p);
+    if c >= u64::from(e) {
+        return 0;
+    }
+    let den = mul_mod(unit_factorial(k, p, q), unit_factorial(n - k, p, q), q);
+    let units = mul_mod(
+        unit_factorial(n, p, q),
+        pow_mod(den, totient(p, q) - 1, q),
+        q,
+    );
+    mul_mod(p.pow(c as u32), units, q)
+}
+
+/// Calculate `n! / p^v mod q`, where `p^v` is the largest power of `p`
+/// dividing `n!`, and `q` is a power of `p`.
+fn unit_factorial(mut n: u64, p: u64, q: u64) -> u64 {
+    // The units modulo `q` multiply to -1, except modulo 2ᵉ for e ≥ 3, where
+    // they multiply to 1.
+    let wilson = if p == 2 && q >= 8 { 1 } else { q - 1 };
+    let mut r = 1;
+    while n > 0 {
+        // `n!` is the product of the units up to `n`, then `p^⌊n/p⌋·⌊n/p⌋!`.
+        if (n / q) % 2 == 1 {
+            r = mul_mod(r, wilson, q);
+        }
+        for i in 1..=n % q {
+            if i % p != 0 {
+                r = mul_mod(r, i, q);
+            }
+        }
+        n /= p;
+    }
+    r
+}
@@ -0,0 +1,257 @@
+use crate::{Integer, Logarithms};
+use core::iter::FusedIterator;
+use num_traits::{CheckedAdd, CheckedMul, CheckedSub};
+
+/// Provides methods to work with the digits of an integer in any base.
+///
+/// The digits of a negative number are those of its magnitude, and zero has
+/// the single digit `0`.  All of the methods panic if `base` is less than 2.
+pub trait Digits: Integer + Clone {
+    /// Returns an iterator over the digits of `self` in the given `base`,
+    /// least significant first.
+    ///
+    /// The iterator is double-ended, so `.rev()` gives the digits most
+    /// significant first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_integer::Digits;
+    ///
+    /// let d: Vec<u32> = 1234u32.digits(&10).collect();
+    /// assert_eq!(d, [4, 3, 2, 1]);
+    ///
+    /// let d: Vec<i16> = (-0x7f3i16).digits(&16).rev().collect();
+    /// assert_eq!(d, [7, 15, 3]);
+    ///
+    /// assert_eq!(0u8.digits(&2).collect::<Vec<_>>(), [0]);
+    /// ```
+    #[inline]
+    fn digits(&self, base: &Self) -> DigitIter<Self> {
+        DigitIter::new(self.clone(), base.clone())
+    }
+
+    /// Returns the number of digits of `self` in the given `base`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_integer::Digits;
+    ///
+    /// assert_eq!(0u32.digit_count(&10), 1);
+    /// assert_eq!(999u32.digit_count(&10), 3);
+    /// assert_eq!(1000u32.digit_count(&10), 4);
+    /// assert_eq!(std::i64::MIN.digit_count(&2), 64);
+    /// ```
+    fn digit_count(&self, base: &Self) -> u32 {
+        self.digits(base).len
+    }
+
+    /// Returns the sum of the digits of `self` in the given `base`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_integer::Digits;
+    ///
+    /// assert_eq!(1234u32.digit_sum(&10), 10);
+    /// assert_eq!((-1234i32).digit_sum(&10), 10);
+    /// assert_eq!(std::u64::MAX.digit_sum(&2), 64);
+    /// ```
+    fn digit_sum(&self, base: &Self) -> Self {
+        self.digits(base).fold(Self::zero(), |sum, d| sum + d)
+    }
+
+    /// Returns `self` with its digits in the given `base` reversed, keeping
+    /// its sign, or `None` if that overflows.
+    ///
+    /// Trailing zeros become leading zeros, so they're dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_integer::Digits;
+    ///
+    /// assert_eq!(1230u32.reverse_digits(&10), Some(321));
+    /// assert_eq!((-123i32).reverse_digits(&10), Some(-321));
+    /// assert_eq!(0b1101u8.reverse_digits(&2), Some(0b1011));
+    /// assert_eq!(199u8.reverse_digits(&10), None);
+    /// ```
+    fn reverse_digits(&self, base: &Self) -> Option<Self>
+    where
+        Self: CheckedAdd + CheckedMul + CheckedSub,
+    {
+        let negative = *self < Self::zero();
+        let mut r = Self::zero();
+        for d in self.digits(base) {
+            r = r.checked_mul(base)?;
+            r = if negative {
+                r.checked_sub(&d)?
+            } else {
+                r.checked_add(&d)?
+            };
+        }
+        Some(r)
+    }
+
+    /// Returns `true` if the digits of `self` in the given `base` read the
+    /// same in both directions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_integer::Digits;
+    ///
+    /// assert!(12321u32.is_palindrome(&10));
+    /// assert!(!12320u32.is_palindrome(&10));
+    /// assert!((-7i8).is_palindrome(&10));
+    /// assert!(0x1ee1u16.is_palindrome(&16));
+    /// ```
+    fn is_palindrome(&self, base: &Self) -> bool {
+        let digits = self.digits(base);
+        digits.clone().eq(digits.rev())
+    }
+
+    /// Builds a non-negative integer from its digits in the given `base`,
+    /// most significant first.
+    ///
+    /// Returns `None` if a digit is negative or not less than `base`, or if
+    /// the result overflows.  An empty iterator gives zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_integer::Digits;
+    ///
+    /// assert_eq!(u32::from_digits(vec![1, 2, 3, 4], &10), Some(1234));
+    /// assert_eq!(u32::from_digits(1234u32.digits(&7).rev(), &7), Some(1234));
+    /// assert_eq!(u8::from_digits(vec![2, 5, 6], &10), None);
+    /// assert_eq!(i8::from_digits(vec![1, -2], &10), None);
+    /// ```
+    fn from_digits<I>(digits: I, base: &Self) -> Option<Self>
+    where
+        I: IntoIterator<Item = Self>,
+        Self: CheckedAdd + CheckedMul,
+    {
+        assert!(*base > Self::one(), "digit base must be at least 2");
+        let mut r = Self::zero();
+        for d in digits {
+            if d < Self::zero() || d >= *base {
+                return None;
+            }
+            r = r.checked_mul(base)?.checked_add(&d)?;
+        }
+        Some(r)
+    }
+}
+
+/// An iterator over the digits of an integer, least significant first.
+///
+/// This is created by [`Digits::digits`](trait.Digits.html#method.digits).
+#[derive(Clone, Debug)]
+pub struct DigitIter<T> {
+    x: T,
+    base: T,
+    // The place value of the most significant remaining digit, with the sign
+    // of `x`, so it's representable even for the most negative `x`.
+    hi: T,
+    len: u32,
+}
+
+impl<T: Integer + Clone> DigitIter<T> {
+    fn new(x: T, base: T) -> Self {
+        assert!(base > T::one(), "digit base must be at least 2");
+
+        // `hi·base` doesn't pass `x` as long as `hi` doesn't pass `x / base`.
+        let q = x.clone() / base.clone();
+        let (mut hi, mut len) = if x < T::zero() {
+            (T::zero() - T::one(), 1)
+        } else {
+            (T::one(), 1)
+        };
+        while (x < T::zero() && hi >= q) || (x >= T::zero() && hi <= q) {
+            hi = hi * base.clone();
+            len += 1;
+        }
+        DigitIter { x, base, hi, len }
+    }
+}
+
+impl<T: Integer + Clone> Iterator for DigitIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        let (q, r) = self.x.div_rem(&self.base);
+        self.x = q;
+        self.hi = self.hi.clone() / self.base.clone();
+        Some(if r < T::zero() { T::zero() - r } else { r })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len as usize;
+        (len, Some(len))
+    }
+}
+
+impl<T: Integer + Clone> DoubleEndedIterator for DigitIter<T> {
+    fn next_back(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        let (d, r) = self.x.div_rem(&self.hi);
+        self.x = r;
+        self.hi = self.hi.clone() / self.base.clone();
+        Some(d)
+    }
+}
+
+impl<T: Integer + Clone> ExactSizeIterator for DigitIter<T> {}
+
+impl<T: Integer + Clone> FusedIterator for DigitIter<T> {}
+
+macro_rules! signed_digits {
+    ($T:ty, $U:ty) => {
+        impl Digits for $T {
+            #[inline]
+            fn digit_count(&self, base: &Self) -> u32 {
+                assert!(*base > 1, "digit base must be at least 2");
+                (self.wrapping_abs() as $U).digit_count(&(*base as $U))
+            }
+        }
+    };
+}
+
+signed_digits!(i8, u8);
+signed_digits!(i16, u16);
+signed_digits!(i32, u32);
+signed_digits!(i64, u64);
+signed_digits!(i128, u128);
+signed_digits!(isize, usize);
+
+macro_rules! unsigned_digits {
+    ($T:ty) => {
+        impl Digits for $T {
+            #[inline]
+            fn digit_count(&self, base: &Self) -> u32 {
+                assert!(*base > 1, "digit base must be at least 2");
+                match Logarithms::checked_ilog(self, base) {
+                    Some(log) => log + 1,
+                    None => 1,
+                }
+            }
+        }
+    };
+}
+
+unsigned_digits!(u8);
+unsigned_digits!(u16);
+unsigned_digits!(u32);
+unsigned_digits!(u64);
+unsigned_digits!(u128);
+unsigned_digits!(usize);
@@ -0,0 +1,173 @@
+use crate::primes::{factorize, Factorization, MAX_FACTORS};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// An iterator over the divisors of a number, in no particular order.
+///
+/// This is created by [`divisors`](fn.divisors.html) or
+/// [`Factorization::divisors`](struct.Factorization.html#method.divisors).
+#[derive(Clone, Debug)]
+pub struct Divisors {
+    factors: Factorization,
+    exponents: [u32; MAX_FACTORS],
+    next: Option<u64>,
+}
+
+impl Factorization {
+    /// Returns an iterator over all of the divisors of the factored number,
+    /// in no particular order.
+    pub fn divisors(&self) -> Divisors {
+        Divisors {
+            factors: *self,
+            exponents: [0; MAX_FACTORS],
+            next: Some(1),
+        }
+    }
+}
+
+impl Iterator for Divisors {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        let d = self.next?;
+
+        // Count up the exponents like an odometer, multiplying in the next
+        // prime power and dividing out the ones that wrap back to zero.
+        let mut next = None;
+        let mut n = d;
+        for (i, &(p, e)) in self.factors.iter().enumerate() {
+            if self.exponents[i] < e {
+                self.exponents[i] += 1;
+                next = Some(n * p);
+                break;
+            }
+            self.exponents[i] = 0;
+            n /= p.pow(e);
+        }
+        self.next = next;
+        Some(d)
+    }
+}
+
+/// Returns an iterator over all of the divisors of `n`, in no particular order.
+///
+/// # Panics
+///
+/// Panics if `n` is zero.
+///
+/// # Examples
+///
+/// ```
+/// use num_integer::divisors;
+///
+/// let mut d: Vec<u64> = divisors(12).collect();
+/// d.sort();
+/// assert_eq!(d, [1, 2, 3, 4, 6, 12]);
+/// ```
+pub fn divisors(n: u64) -> Divisors {
+    factorize(n).divisors()
+}
+
+/// Returns all of the divisors of `n` in increasing order.
+///
+/// # Panics
+///
+/// Panics if `n` is zero.
+///
+/// # Examples
+///
+/// ```
+/// use num_integer::sorted_divisors;
+///
+/// assert_eq!(sorted_divisors(1), [1]);
+/// assert_eq!(sorted_divisors(28), [1, 2, 4, 7, 14, 28]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn sorted_divisors(n: u64) -> Vec<u64> {
+    let f = factorize(n);
+    let mut v = Vec::with_capacity(count(&f) as usize);
+    v.extend(f.divisors());
+    v.sort_unstable();
+    v
+}
+
+fn count(f: &Factorization) -> u64 {
+    f.iter().map(|&(_, e)| u64::from(e) + 1).product()
+}
+
+/// Returns the number of divisors of `n` -- `σ₀(n)`.
+///
+/// # Panics
+///
+/// Panics if `n` is zero.
+///
+/// # Examples
+///
+/// ```
+/// use num_integer::divisor_count;
+///
+/// assert_eq!(divisor_count(1), 1);
+/// assert_eq!(divisor_count(12), 6);
+/// assert_eq!(divisor_count(97), 2);
+/// ```
+pub fn divisor_count(n: u64) -> u64 {
+    count(&factorize(n))
+}
+
+/// Returns the sum of the divisors of `n` -- `σ₁(n)`.
+///
+/// The result can exceed `u64::MAX` for large `n`, but always fits in a `u128`.
+///
+/// # Panics
+///
+/// Panics if `n` is zero.
+///
+/// # Examples
+///
+/// ```
+/// use num_integer::divisor_sum;
+///
+/// assert_eq!(divisor_sum(1), 1);
+/// assert_eq!(divisor_sum(12), 28);
+/// assert_eq!(divisor_sum(28), 56); // a perfect number
+/// ```
+pub fn divisor_sum(n: u64) -> u128 {
+    factorize(n)
+        .iter()
+        .map(|&(p, e)| {
+            let p = u128::from(p);
+            (0..e).fold(1, |s, _| s * p + 1)
+        })
+        .product()
+}
+
+/// Returns the sum of the `k`th powers of the divisors of `n` -- `σₖ(n)`,
+/// or `None` if that overflows a `u128`.
+///
+/// # Panics
+///
+/// Panics if `n` is zero.
+///
+/// # Examples
+///
+/// ```
+/// use num_integer::divisor_sigma;
+///
+/// assert_eq!(divisor_sigma(12, 0), Some(6));
+/// assert_eq!(divisor_sigma(12, 1), Some(28));
+/// assert_eq!(divisor_sigma(12, 2), Some(210));
+/// assert_eq!(divisor_sigma(std::u64::MAX, 3), None);
+/// ```
+pub fn divisor_sigma(n: u64, k: u32) -> Option<u128> {
+    let mut sigma: u128 = 1;
+    for &(p, e) in &factorize(n) {
+        // 1 + pᵏ + p²ᵏ + ... + pᵉᵏ, by Horner's rule
+        let pk = num_traits::checked_pow(u128::from(p), k as usize)?;
+        let mut s: u128 = 1;
+        for _ in 0..e {
+            s = s.checked_mul(pk)?.checked_add(1)?;
+        }
+        sigma = sigma.checked_mul(s)?;
+    }
+    Some(sigma)
+}
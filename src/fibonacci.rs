@@ -0,0 +1,331 @@
+use crate::Integer;
+use num_traits::{CheckedAdd, CheckedMul, CheckedSub};
+
+/// Returns `(F(n), F(n+1))` by fast doubling, built from the given operations,
+/// which may fail with `None`.
+///
+/// From `(F(k), F(k+1))` this steps to `F(2k) = F(k)·(2F(k+1) - F(k))` and
+/// `F(2k+1) = F(k)² + F(k+1)²`, then to `F(2k+2)` if the next bit of `n` is
+/// set.  Every intermediate value is at most `F(n+1)`.
+fn fibonacci_pair<T, A, S, M>(n: u64, add: A, sub: S, mul: M) -> Option<(T, T)>
+where
+    T: Integer + Clone,
+    A: Fn(T, T) -> Option<T>,
+    S: Fn(T, T) -> Option<T>,
+    M: Fn(T, T) -> Option<T>,
+{
+    let (mut a, mut b) = (T::zero(), T::one());
+    for i in (0..64 - n.leading_zeros()).rev() {
+        let two_b = add(b.clone(), b.clone())?;
+        let c = mul(a.clone(), sub(two_b, a.clone())?)?;
+        let d = add(mul(a.clone(), a)?, mul(b.clone(), b)?)?;
+        if (n >> i) & 1 == 1 {
+            a = d.clone();
+            b = add(c, d)?;
+        } else {
+            a = c;
+            b = d;
+        }
+    }
+    Some((a, b))
+}
+
+/// Returns `(F(n-1), F(n))` for `n > 0`, with the given operations.
+#[inline]
+fn fibonacci_pair_before<T, A, S, M>(n: u64, add: A, sub: S, mul: M) -> Option<(T, T)>
+where
+    T: Integer + Clone,
+    A: Fn(T, T) -> Option<T>,
+    S: Fn(T, T) -> Option<T>,
+    M: Fn(T, T) -> Option<T>,
+{
+    debug_assert!(n > 0);
+    fibonacci_pair(n - 1, add, sub, mul)
+}
+
+/// Calculate the `n`th Fibonacci number, `F(n)`, where `F(0) = 0`, `F(1) = 1`
+/// and `F(n) = F(n-1) + F(n-2)`.
+///
+/// This uses the fast doubling identities, so it takes `O(log n)`
+/// multiplications, and no intermediate value is larger than the result.
+///
+/// Note that this might overflow, depending on `T`. For the primitive integer
+/// types, the following n are the largest ones possible such that there will
+/// be no overflow:
+///
+/// type | n
+/// -----|----
+/// u8   |  13
+/// i8   |  11
+/// u16  |  24
+/// i16  |  23
+/// u32  |  47
+/// i32  |  46
+/// u64  |  93
+/// i64  |  92
+/// u128 | 186
+/// i128 | 184
+///
+/// For larger n, consider using [`checked_fibonacci`](fn.checked_fibonacci.html)
+/// or a bigint type for `T`.
+///
+/// # Examples
+///
+/// ```
+/// use num_integer::fibonacci;
+///
+/// assert_eq!(fibonacci::<u32>(0), 0);
+/// assert_eq!(fibonacci::<u32>(10), 55);
+/// assert_eq!(fibonacci::<u8>(13), 233);
+/// assert_eq!(fibonacci::<u64>(93), 12_200_160_415_121_876_738);
+/// ```
+pub fn fibonacci<T: Integer + Clone>(n: u64) -> T {
+    if n == 0 {
+        return T::zero();
+    }
+    let pair = fibonacci_pair_before(
+        n,
+        |a: T, b: T| Some(a + b),
+        |a: T, b: T| Some(a - b),
+        |a: T, b: T| Some(a * b),
+    );
+    match pair {
+        Some((_, f)) => f,
+        None => unreachable!(),
+    }
+}
+
+/// Calculate the `n`th Fibonacci number, or `None` if it overflows `T` --
+/// see [`fibonacci`](fn.fibonacci.html).
+///
+/// # Examples
+///
+/// ```
+/// use num_integer::checked_fibonacci;
+///
+/// assert_eq!(checked_fibonacci::<u8>(13), Some(233));
+/// assert_eq!(checked_fibonacci::<u8>(14), None);
+/// assert_eq!(checked_fibonacci::<i128>(184), Some(127_127_879_743_834_334_146_972_278_486_287_885_163));
+/// assert_eq!(checked_fibonacci::<i128>(185), None);
+/// ```
+pub fn checked_fibonacci<T>(n: u64) -> Option<T>
+where
+    T: Integer + Clone + CheckedAdd + CheckedSub + CheckedMul,
+{
+    if n == 0 {
+        return Some(T::zero());
+    }
+    let pair = fibonacci_pair_before(
+        n,
+        |a: T, b: T| a.checked_add(&b),
+        |a: T, b: T| a.checked_sub(&b),
+        |a: T, b: T| a.checked_mul(&b),
+    );
+    pair.map(|(_, f)| f)
+}
+
+/// Calculate the `n`th Lucas number, `L(n)`, where `L(0) = 2`, `L(1) = 1`
+/// and `L(n) = L(n-1) + L(n-2)`.
+///
+/// This is `F(n-1) + F(n+1)`, found by fast doubling in `O(log n)`
+/// multiplications, and no intermediate value is larger than the result.
+///
+/// Note that this might overflow, depending on `T`. For the primitive integer
+/// types, the following n are the largest ones possible such that there will
+/// be no overflow:
+///
+/// type | n
+/// -----|----
+/// u8   |  11
+/// i8   |  10
+/// u16  |  23
+/// i16  |  21
+/// u32  |  46
+/// i32  |  44
+/// u64  |  92
+/// i64  |  90
+/// u128 | 184
+/// i128 | 182
+///
+/// # Examples
+///
+/// ```
+/// use num_integer::lucas;
+///
+/// assert_eq!(lucas::<u32>(0), 2);
+/// assert_eq!(lucas::<u32>(10), 123);
+/// assert_eq!(lucas::<u8>(11), 199);
+/// ```
+pub fn lucas<T: Integer + Clone>(n: u64) -> T {
+    let two = T::one() + T::one();
+    if n == 0 {
+        return two;
+    }
+    let pair = fibonacci_pair_before(
+        n,
+        |a: T, b: T| Some(a + b),
+        |a: T, b: T| Some(a - b),
+        |a: T, b: T| Some(a * b),
+    );
+    match pair {
+        // L(n) = F(n-1) + F(n+1) = F(n-1) + F(n) + F(n-1)
+        Some((f0, f1)) => f0.clone() + f1 + f0,
+        None => unreachable!(),
+    }
+}
+
+/// Calculate the `n`th Lucas number, or `None` if it overflows `T` --
+/// see [`lucas`](fn.lucas.html).
+///
+/// # Examples
+///
+/// ```
+/// use num_integer::checked_lucas;
+///
+/// assert_eq!(checked_lucas::<u8>(11), Some(199));
+/// assert_eq!(checked_lucas::<u8>(12), None);
+/// ```
+pub fn checked_lucas<T>(n: u64) -> Option<T>
+where
+    T: Integer + Clone + CheckedAdd + CheckedSub + CheckedMul,
+{
+    let two = T::one() + T::one();
+    if n == 0 {
+        return Some(two);
+    }
+    let (f0, f1) = fibonacci_pair_before(
+        n,
+        |a: T, b: T| a.checked_add(&b),
+        |a: T, b: T| a.checked_sub(&b),
+        |a: T, b: T| a.checked_mul(&b),
+    )?;
+    f0.checked_add(&f1)?.checked_add(&f0)
+}
+
+/// Returns `(a + b) mod m` for `a, b < m`, without overflow.
+fn add_mod<T: Integer + Clone>(a: T, b: T, m: &T) -> T {
+    let room = m.clone() - b.clone();
+    if a >= room {
+        a - room
+    } else {
+        a + b
+    }
+}
+
+/// Returns `(a - b) mod m` for `a, b < m`, without overflow.
+fn sub_mod<T: Integer + Clone>(a: T, b: T, m: &T) -> T {
+    if a >= b {
+        a - b
+    } else {
+        m.clone() - (b - a)
+    }
+}
+
+/// Returns `a·b mod m` for `a, b < m`, without overflow, by doubling and
+/// adding.
+fn mul_mod<T: Integer + Clone>(mut a: T, mut b: T, m: &T) -> T {
+    let two = T::one() + T::one();
+    let mut r = T::zero();
+    while !b.is_zero() {
+        if b.is_odd() {
+            r = add_mod(r, a.clone(), m);
+        }
+        a = add_mod(a.clone(), a, m);
+        b = b / two.clone();
+    }
+    r
+}
+
+/// Calculate the `n`th Fibonacci number modulo `m`, `F(n) mod m`.
+///
+/// This takes `O(log n)` modular multiplications by fast doubling, and it
+/// never overflows, even for `m` close to the maximum of `T`.
+///
+/// # Panics
+///
+/// Panics if `m` is not positive.
+///
+/// # Examples
+///
+/// ```
+/// use num_integer::fibonacci_mod;
+///
+/// assert_eq!(fibonacci_mod(10, 7u32), 55 % 7);
+/// assert_eq!(fibonacci_mod(1_000_000_000_000, 1_000_000_007u64), 730_695_249);
+/// // The Pisano period for 10 is 60.
+/// assert_eq!(fibonacci_mod(60, 10u8), 0);
+/// assert_eq!(fibonacci_mod(61, 10u8), 1);
+/// ```
+pub fn fibonacci_mod<T: Integer + Clone>(n: u64, m: T) -> T {
+    assert!(m > T::zero(), "the modulus must be positive");
+    if m.is_one() {
+        return T::zero();
+    }
+    let pair = fibonacci_pair(
+        n,
+        |a, b| Some(add_mod(a, b, &m)),
+        |a, b| Some(sub_mod(a, b, &m)),
+        |a, b| Some(mul_mod(a, b, &m)),
+    );
+    match pair {
+        Some((f, _)) => f,
+        None => unreachable!(),
+    }
+}
+
+/// An iterator over the Fibonacci or Lucas numbers, which ends before the
+/// first one that overflows `T`.
+///
+/// # Examples
+///
+/// ```
+/// use num_integer::Fibonacci;
+///
+/// let f: Vec<u8> = Fibonacci::new().collect();
+/// assert_eq!(f, [0, 1, 1, 2, 3, 5, 8, 13, 21, 34, 55, 89, 144, 233]);
+///
+/// let l: Vec<i32> = Fibonacci::lucas().take(6).collect();
+/// assert_eq!(l, [2, 1, 3, 4, 7, 11]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Fibonacci<T> {
+    a: Option<T>,
+    b: Option<T>,
+}
+
+impl<T: Integer + Clone> Fibonacci<T> {
+    /// Creates an iterator over the Fibonacci numbers, starting from `F(0) = 0`.
+    pub fn new() -> Self {
+        Fibonacci {
+            a: Some(T::zero()),
+            b: Some(T::one()),
+        }
+    }
+
+    /// Creates an iterator over the Lucas numbers, starting from `L(0) = 2`.
+    pub fn lucas() -> Self {
+        Fibonacci {
+            a: Some(T::one() + T::one()),
+            b: Some(T::one()),
+        }
+    }
+}
+
+impl<T: Integer + Clone> Default for Fibonacci<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Integer + Clone + CheckedAdd> Iterator for Fibonacci<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let a = self.a.take()?;
+        self.a = self.b.take();
+        if let Some(ref b) = self.a {
+            self.b = a.checked_add(b);
+        }
+        Some(a)
+    }
+}
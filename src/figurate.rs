@@ -0,0 +1,428 @@
+use crate::{Integer, Roots};
+use num_traits::{CheckedAdd, CheckedMul};
+
+/// Checks that `s` is a valid number of sides.
+#[inline]
+fn check_sides<T: Integer>(s: &T) {
+    let three = T::one() + T::one() + T::one();
+    assert!(*s >= three, "a polygon must have at least 3 sides");
+}
+
+/// Checks that `s` and `n` are valid arguments for a figurate number.
+#[inline]
+fn check_args<T: Integer>(s: &T, n: &T) {
+    check_sides(s);
+    assert!(
+        *n >= T::zero(),
+        "figurate numbers need a non-negative index"
+    );
+}
+
+/// Checks that `s` and `x` are valid arguments for a figurate root.
+#[inline]
+fn check_root_args<T: Integer>(s: &T, x: &T) {
+    check_sides(s);
+    assert!(*x >= T::zero(), "figurate roots need a non-negative `x`");
+}
+
+/// Splits the factors of `m(m+1)(m+2)` so their product is `m(m+1)(m+2)/6`.
+fn tetrahedral_factors<T: Integer + Clone>(mut f: [T; 3]) -> [T; 3] {
+    let two = T::one() + T::one();
+    let three = two.clone() + T::one();
+    // Of three consecutive integers, one is a multiple of 3, and one of the
+    // others is even unless the multiple of 3 is also the only even one.
+    for x in f.iter_mut() {
+        if x.is_multiple_of(&three) {
+            *x = x.clone() / three;
+            break;
+        }
+    }
+    for x in f.iter_mut() {
+        if x.is_even() {
+            *x = x.clone() / two;
+            break;
+        }
+    }
+    f
+}
+
+/// Returns the largest `n ≥ 0` with `f(n) ≤ x`, starting from the estimate
+/// `n`, where `f` is increasing and `None` means it overflowed.
+fn figurate_root<T, F>(mut n: T, x: &T, f: F) -> T
+where
+    T: Integer + Clone,
+    F: Fn(T) -> Option<T>,
+{
+    while n > T::zero() && f(n.clone()).map_or(true, |y| y > *x) {
+        n = n - T::one();
+    }
+    loop {
+        let m = n.clone() + T::one();
+        match f(m.clone()) {
+            Some(ref y) if y <= x => n = m,
+            _ => return n,
+        }
+    }
+}
+
+/// Calculate the `n`th triangular number, `n(n+1)/2`, the sum of the
+/// integers from 1 to `n`.
+///
+/// This halves the even factor first, so it only overflows if the result
+/// does.
+///
+/// # Panics
+///
+/// Panics if `n` is negative.
+///
+/// # Examples
+///
+/// ```
+/// use num_integer::triangular;
+///
+/// assert_eq!(triangular(0), 0);
+/// assert_eq!(triangular(4), 10);
+/// assert_eq!(triangular(22u8), 253);
+/// ```
+pub fn triangular<T: Integer + Clone>(n: T) -> T {
+    assert!(n >= T::zero(), "figurate numbers need a non-negative index");
+    let two = T::one() + T::one();
+    let m = n.clone() + T::one();
+    if n.is_even() {
+        n / two * m
+    } else {
+        n * (m / two)
+    }
+}
+
+/// Calculate the `n`th triangular number, or `None` if it overflows `T` --
+/// see [`triangular`](fn.triangular.html).
+///
+/// # Examples
+///
+/// ```
+/// use num_integer::checked_triangular;
+///
+/// assert_eq!(checked_triangular(22u8), Some(253));
+/// assert_eq!(checked_triangular(23u8), None);
+/// ```
+pub fn checked_triangular<T>(n: T) -> Option<T>
+where
+    T: Integer + Clone + CheckedAdd + CheckedMul,
+{
+    assert!(n >= T::zero(), "figurate numbers need a non-negative index");
+    let two = T::one() + T::one();
+    let m = n.checked_add(&T::one())?;
+    if n.is_even() {
+        (n / two).checked_mul(&m)
+    } else {
+        n.checked_mul(&(m / two))
+    }
+}
+
+/// Returns the largest `n` whose triangular number is at most `x`, so
+/// `triangular(n) ≤ x < triangular(n+1)`.
+///
+/// This is `⌊(√(8x+1) - 1)/2⌋`.  It's estimated with an integer square root
+/// and then corrected against the neighbouring triangular numbers, without
+/// overflowing, even for `x` near the maximum of `T`.
+///
+/// # Panics
+///
+/// Panics if `x` is negative.
+///
+/// # Examples
+///
+/// Finding the row and column of the `k`th element of a packed lower
+/// triangular matrix, stored row by row:
+///
+/// ```
+/// use num_integer::{triangular, triangular_root};
+///
+/// let k = 13u32;
+/// let row = triangular_root(k);
+/// let col = k - triangular(row);
+/// assert_eq!((row, col), (4, 3));
+///
+/// assert_eq!(triangular_root(std::u64::MAX), 6_074_000_999);
+/// ```
+pub fn triangular_root<T>(x: T) -> T
+where
+    T: Roots + Clone + CheckedAdd + CheckedMul,
+{
+    let three = T::one() + T::one() + T::one();
+    polygonal_root(three, x)
+}
+
+/// Returns `Some(n)` if `x` is the `n`th triangular number, or `None`
+/// otherwise.
+///
+/// # Examples
+///
+/// ```
+/// use num_integer::is_triangular;
+///
+/// assert_eq!(is_triangular(0), Some(0));
+/// assert_eq!(is_triangular(10), Some(4));
+/// assert_eq!(is_triangular(11), None);
+/// assert_eq!(is_triangular(-1), None);
+/// ```
+pub fn is_triangular<T>(x: T) -> Option<T>
+where
+    T: Roots + Clone + CheckedAdd + CheckedMul,
+{
+    let three = T::one() + T::one() + T::one();
+    is_polygonal(three, x)
+}
+
+/// Calculate the `n`th `s`-gonal number, `((s-2)n² - (s-4)n)/2`.
+///
+/// For `s` = 3, 4, 5, 6 these are the triangular, square, pentagonal and
+/// hexagonal numbers.  This is computed as `n + (s-2)·triangular(n-1)`, so
+/// it only overflows if the result does.
+///
+/// # Panics
+///
+/// Panics if `s` is less than 3 or `n` is negative.
+///
+/// # Examples
+///
+/// ```
+/// use num_integer::polygonal;
+///
+/// assert_eq!(polygonal(3, 4), 10);
+/// assert_eq!(polygonal(4, 4), 16);
+/// assert_eq!(polygonal(5, 4), 22);
+/// assert_eq!(polygonal(6, 4), 28);
+/// ```
+pub fn polygonal<T: Integer + Clone>(s: T, n: T) -> T {
+    check_args(&s, &n);
+    if n.is_zero() {
+        return n;
+    }
+    let a = s - (T::one() + T::one());
+    let t = triangular(n.clone() - T::one());
+    n + a * t
+}
+
+/// Calculate the `n`th `s`-gonal number, or `None` if it overflows `T` --
+/// see [`polygonal`](fn.polygonal.html).
+///
+/// # Examples
+///
+/// ```
+/// use num_integer::checked_polygonal;
+///
+/// assert_eq!(checked_polygonal(5u8, 13), Some(247));
+/// assert_eq!(checked_polygonal(5u8, 14), None);
+/// ```
+pub fn checked_polygonal<T>(s: T, n: T) -> Option<T>
+where
+    T: Integer + Clone + CheckedAdd + CheckedMul,
+{
+    check_args(&s, &n);
+    if n.is_zero() {
+        return Some(n);
+    }
+    let a = s - (T::one() + T::one());
+    let t = checked_triangular(n.clone() - T::one())?;
+    n.checked_add(&a.checked_mul(&t)?)
+}
+
+/// Returns the largest `n` whose `s`-gonal number is at most `x`, so
+/// `polygonal(s, n) ≤ x < polygonal(s, n+1)`.
+///
+/// This is `⌊(√(8(s-2)x + (s-4)²) + s-4) / 2(s-2)⌋`.  It's estimated from
+/// the integer square root `√(2x/(s-2))` and then corrected against the
+/// neighbouring `s`-gonal numbers, without overflowing, even for `x` near the
+/// maximum of `T`.
+///
+/// # Panics
+///
+/// Panics if `s` is less than 3 or `x` is negative.
+///
+/// # Examples
+///
+/// ```
+/// use num_integer::polygonal_root;
+///
+/// assert_eq!(polygonal_root(4, 99), 9);
+/// assert_eq!(polygonal_root(4, 100), 10);
+/// assert_eq!(polygonal_root(5, 250u8), 13);
+/// ```
+pub fn polygonal_root<T>(s: T, x: T) -> T
+where
+    T: Roots + Clone + CheckedAdd + CheckedMul,
+{
+    check_root_args(&s, &x);
+    let two = T::one() + T::one();
+    let a = s.clone() - two.clone();
+
+    // n is within about 1 of √(2x/(s-2)), but 2x may not fit.
+    let y = x.clone() / a;
+    let n = match y.checked_add(&y) {
+        Some(y2) => y2.sqrt(),
+        None => (y / two.clone()).sqrt() * two,
+    };
+    figurate_root(n, &x, |n| checked_polygonal(s.clone(), n))
+}
+
+/// Returns `Some(n)` if `x` is the `n`th `s`-gonal number, or `None`
+/// otherwise.
+///
+/// # Panics
+///
+/// Panics if `s` is less than 3.
+///
+/// # Examples
+///
+/// ```
+/// use num_integer::is_polygonal;
+///
+/// assert_eq!(is_polygonal(5, 22), Some(4));
+/// assert_eq!(is_polygonal(5, 23), None);
+/// assert_eq!(is_polygonal(6, 28), Some(4));
+/// ```
+pub fn is_polygonal<T>(s: T, x: T) -> Option<T>
+where
+    T: Roots + Clone + CheckedAdd + CheckedMul,
+{
+    if x < T::zero() {
+        check_sides(&s);
+        return None;
+    }
+    let n = polygonal_root(s.clone(), x.clone());
+    if polygonal(s, n.clone()) == x {
+        Some(n)
+    } else {
+        None
+    }
+}
+
+/// Calculate the `n`th `s`-gonal pyramidal number, `n(n+1)((s-2)n - (s-5))/6`,
+/// the sum of the first `n` `s`-gonal numbers.
+///
+/// For `s` = 3 and 4 these are the tetrahedral and square pyramidal numbers.
+/// This is computed as `triangular(n) + (s-2)·tetrahedral(n-1)`, with the
+/// divisions done first, so it only overflows if the result does.
+///
+/// # Panics
+///
+/// Panics if `s` is less than 3 or `n` is negative.
+///
+/// # Examples
+///
+/// ```
+/// use num_integer::pyramidal;
+///
+/// assert_eq!(pyramidal(3, 4), 20);
+/// assert_eq!(pyramidal(4, 4), 30);
+/// assert_eq!(pyramidal(5, 4), 40);
+/// ```
+pub fn pyramidal<T: Integer + Clone>(s: T, n: T) -> T {
+    check_args(&s, &n);
+    if n.is_zero() {
+        return n;
+    }
+    let a = s - (T::one() + T::one());
+    let m = n.clone() - T::one();
+    let f = tetrahedral_factors([m, n.clone(), n.clone() + T::one()]);
+    let t = f[0].clone() * f[1].clone() * f[2].clone();
+    triangular(n) + a * t
+}
+
+/// Calculate the `n`th `s`-gonal pyramidal number, or `None` if it overflows
+/// `T` -- see [`pyramidal`](fn.pyramidal.html).
+///
+/// # Examples
+///
+/// ```
+/// use num_integer::checked_pyramidal;
+///
+/// assert_eq!(checked_pyramidal(4u8, 8), Some(204));
+/// assert_eq!(checked_pyramidal(4u8, 9), None);
+/// ```
+pub fn checked_pyramidal<T>(s: T, n: T) -> Option<T>
+where
+    T: Integer + Clone + CheckedAdd + CheckedMul,
+{
+    check_args(&s, &n);
+    if n.is_zero() {
+        return Some(n);
+    }
+    let a = s - (T::one() + T::one());
+    let m = n.clone() - T::one();
+    let f = tetrahedral_factors([m, n.clone(), n.checked_add(&T::one())?]);
+    let t = f[0].checked_mul(&f[1])?.checked_mul(&f[2])?;
+    checked_triangular(n)?.checked_add(&a.checked_mul(&t)?)
+}
+
+/// Returns the largest `n` whose `s`-gonal pyramidal number is at most `x`,
+/// so `pyramidal(s, n) ≤ x < pyramidal(s, n+1)`.
+///
+/// This is estimated from the integer cube root `∛(6x/(s-2))` and then
+/// corrected against the neighbouring pyramidal numbers, without
+/// overflowing, even for `x` near the maximum of `T`.
+///
+/// # Panics
+///
+/// Panics if `s` is less than 3 or `x` is negative.
+///
+/// # Examples
+///
+/// ```
+/// use num_integer::pyramidal_root;
+///
+/// assert_eq!(pyramidal_root(4, 29), 3);
+/// assert_eq!(pyramidal_root(4, 30), 4);
+/// assert_eq!(pyramidal_root(3, std::u32::MAX), 2952);
+/// ```
+pub fn pyramidal_root<T>(s: T, x: T) -> T
+where
+    T: Roots + Clone + CheckedAdd + CheckedMul,
+{
+    check_root_args(&s, &x);
+    let two = T::one() + T::one();
+    let three = two.clone() + T::one();
+    let a = s.clone() - two.clone();
+
+    // n is within about 1 of ∛(6x/(s-2)), but 6x may not fit.
+    let y = x.clone() / a;
+    let n = match y.checked_mul(&(two.clone() * three.clone())) {
+        Some(y6) => y6.cbrt(),
+        None => (y / (two.clone() * two.clone()) * three).cbrt() * two,
+    };
+    figurate_root(n, &x, |n| checked_pyramidal(s.clone(), n))
+}
+
+/// Returns `Some(n)` if `x` is the `n`th `s`-gonal pyramidal number, or
+/// `None` otherwise.
+///
+/// # Panics
+///
+/// Panics if `s` is less than 3.
+///
+/// # Examples
+///
+/// ```
+/// use num_integer::is_pyramidal;
+///
+/// assert_eq!(is_pyramidal(3, 20), Some(4));
+/// assert_eq!(is_pyramidal(4, 30), Some(4));
+/// assert_eq!(is_pyramidal(4, 31), None);
+/// ```
+pub fn is_pyramidal<T>(s: T, x: T) -> Option<T>
+where
+    T: Roots + Clone + CheckedAdd + CheckedMul,
+{
+    if x < T::zero() {
+        check_sides(&s);
+        return None;
+    }
+    let n = pyramidal_root(s.clone(), x.clone());
+    if pyramidal(s, n.clone()) == x {
+        Some(n)
+    } else {
+        None
+    }
+}
@@ -17,19 +17,76 @@
 #![doc(html_root_url = "https://docs.rs/num-integer/0.1")]
 #![no_std]
 
+#[cfg(feature = "std")]
+extern crate std as alloc;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+extern crate alloc;
+
 use core::mem;
 use core::ops::Add;
 
-use num_traits::{Num, Signed, Zero};
+use num_traits::{
+    Bounded, CheckedAdd, CheckedMul, CheckedSub, FromPrimitive, Num, Signed, ToPrimitive,
+    WrappingMul, Zero,
+};
 
 mod roots;
-pub use crate::roots::Roots;
-pub use crate::roots::{cbrt, nth_root, sqrt};
+pub use crate::roots::{cbrt, is_perfect_power, nth_root, nth_root_rem, sqrt, sqrt_rem};
+pub use crate::roots::{checked_cbrt, checked_nth_root, checked_sqrt};
+pub use crate::roots::{generic_cbrt, generic_nth_root, generic_sqrt, hypot, norm};
+pub use crate::roots::{nth_root_scaled, sqrt_scaled};
+pub use crate::roots::{Roots, Rounding};
+
+mod logarithms;
+pub use crate::logarithms::Logarithms;
+
+mod digits;
+pub use crate::digits::{DigitIter, Digits};
+
+mod radix;
+pub use crate::radix::{format_radix, RadixDisplay};
+
+mod fibonacci;
+pub use crate::fibonacci::Fibonacci;
+pub use crate::fibonacci::{checked_fibonacci, checked_lucas, fibonacci, fibonacci_mod, lucas};
+
+mod figurate;
+pub use crate::figurate::{checked_polygonal, checked_pyramidal, checked_triangular};
+pub use crate::figurate::{is_polygonal, is_pyramidal, is_triangular};
+pub use crate::figurate::{polygonal, pyramidal, triangular};
+pub use crate::figurate::{polygonal_root, pyramidal_root, triangular_root};
 
 mod average;
 pub use crate::average::Average;
 pub use crate::average::{average_ceil, average_floor};
 
+mod primes;
+pub use crate::primes::{factorize, is_prime, Factorization};
+#[cfg(feature = "alloc")]
+pub use crate::primes::{nth_prime, prime_pi};
+
+mod binomial_mod;
+pub use crate::binomial_mod::binomial_mod;
+
+mod divisors;
+#[cfg(feature = "alloc")]
+pub use crate::divisors::sorted_divisors;
+pub use crate::divisors::{divisor_count, divisor_sigma, divisor_sum, divisors, Divisors};
+
+#[cfg(feature = "alloc")]
+mod sieve;
+#[cfg(feature = "alloc")]
+pub use crate::sieve::{SpfFactors, SpfSieve};
+
+mod multiplicative;
+pub use crate::multiplicative::Multiplicative;
+pub use crate::multiplicative::{core_squarefree, is_squarefree, liouville, mobius, radical};
+#[cfg(feature = "alloc")]
+pub use crate::multiplicative::{
+    core_squarefree_range, liouville_range, mobius_range, radical_range, squarefree_range,
+};
+
 pub trait Integer: Sized + Num + PartialOrd + Ord + Eq {
     /// Floored integer division.
     ///
@@ -340,6 +397,194 @@ pub trait Integer: Sized + Num + PartialOrd + Ord + Eq {
         self.clone() - self.mod_floor(other)
     }
 
+    /// Returns the exponent `k` if `self` is exactly `baseᵏ`, or `None`
+    /// otherwise.
+    ///
+    /// If more than one exponent works, as for `1 = 1ᵏ`, the smallest is
+    /// returned.
+    ///
+    /// # Examples
+    ///
+    /// ~~~
+    /// # use num_integer::Integer;
+    /// assert_eq!(  81.is_power_of(&3),  Some(4));
+    /// assert_eq!(  80.is_power_of(&3),  None);
+    /// assert_eq!(   1.is_power_of(&7),  Some(0));
+    /// assert_eq!(( -8).is_power_of(&-2), Some(3));
+    /// assert_eq!(( -8).is_power_of(&2),  None);
+    /// assert_eq!(   0.is_power_of(&0),  Some(1));
+    /// ~~~
+    fn is_power_of(&self, base: &Self) -> Option<u32>
+    where
+        Self: Clone,
+    {
+        if self.is_one() {
+            return Some(0);
+        }
+        if base.is_zero() || Self::one().is_multiple_of(base) {
+            // The only other powers of 0 and ±1 are themselves.
+            return if self == base { Some(1) } else { None };
+        }
+
+        let mut x = self.clone();
+        let mut k = 0;
+        while !x.is_one() {
+            if x.is_zero() || !x.is_multiple_of(base) {
+                return None;
+            }
+            x = x / base.clone();
+            k += 1;
+        }
+        Some(k)
+    }
+
+    /// Returns the smallest power of `base` greater than or equal to `self`,
+    /// or `None` if it overflows.
+    ///
+    /// This generalizes `next_power_of_two`, including that the result is
+    /// `1` for any `self ≤ 1`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `base` is less than 2.
+    ///
+    /// # Examples
+    ///
+    /// ~~~
+    /// # use num_integer::Integer;
+    /// assert_eq!( 81u8.next_power_of(&3), Some(81));
+    /// assert_eq!( 82u8.next_power_of(&3), Some(243));
+    /// assert_eq!(244u8.next_power_of(&3), None);
+    /// assert_eq!(  0u8.next_power_of(&3), Some(1));
+    /// ~~~
+    fn next_power_of(&self, base: &Self) -> Option<Self>
+    where
+        Self: Clone + CheckedMul,
+    {
+        assert!(*base > Self::one(), "base must be at least 2");
+        let mut p = Self::one();
+        while p < *self {
+            p = p.checked_mul(base)?;
+        }
+        Some(p)
+    }
+
+    /// Returns the largest power of `base` less than or equal to `self`,
+    /// or `None` if `self` is less than 1.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `base` is less than 2.
+    ///
+    /// # Examples
+    ///
+    /// ~~~
+    /// # use num_integer::Integer;
+    /// assert_eq!( 81.prev_power_of(&3), Some(81));
+    /// assert_eq!( 80.prev_power_of(&3), Some(27));
+    /// assert_eq!(  1.prev_power_of(&3), Some(1));
+    /// assert_eq!(  0.prev_power_of(&3), None);
+    /// assert_eq!(std::i8::MAX.prev_power_of(&2), Some(64));
+    /// ~~~
+    fn prev_power_of(&self, base: &Self) -> Option<Self>
+    where
+        Self: Clone + CheckedMul,
+    {
+        assert!(*base > Self::one(), "base must be at least 2");
+        if *self < Self::one() {
+            return None;
+        }
+        let mut p = Self::one();
+        loop {
+            match p.checked_mul(base) {
+                Some(q) if q <= *self => p = q,
+                _ => return Some(p),
+            }
+        }
+    }
+
+    /// Raises `self` to the power of `exp` by squaring, returning `None` if
+    /// the result overflows.
+    ///
+    /// The exponent may be wider than `usize`, and `0`, `1` and `-1` are
+    /// raised to any power without iterating.
+    ///
+    /// Note that the primitive integers also have inherent `checked_pow`
+    /// methods with a `u32` exponent, which take precedence in method-call
+    /// syntax, so call this one by path as `Integer::checked_pow(&x, exp)`.
+    ///
+    /// # Examples
+    ///
+    /// ~~~
+    /// use num_integer::Integer;
+    ///
+    /// assert_eq!(Integer::checked_pow(&3u8, 5), Some(243));
+    /// assert_eq!(Integer::checked_pow(&3u8, 6), None);
+    /// assert_eq!(Integer::checked_pow(&-2i8, 7), Some(-128));
+    /// assert_eq!(Integer::checked_pow(&-1i8, u128::max_value()), Some(-1));
+    /// ~~~
+    fn checked_pow(&self, exp: u128) -> Option<Self>
+    where
+        Self: Clone + CheckedMul,
+    {
+        let (r, overflow) = pow_by_squaring(self, exp, |a, b| match a.checked_mul(b) {
+            Some(x) => (x, false),
+            None => (Self::zero(), true),
+        });
+        if overflow {
+            None
+        } else {
+            Some(r)
+        }
+    }
+
+    /// Raises `self` to the power of `exp` by squaring, saturating at the
+    /// numeric bounds instead of overflowing.
+    ///
+    /// # Examples
+    ///
+    /// ~~~
+    /// use num_integer::Integer;
+    ///
+    /// assert_eq!(Integer::saturating_pow(&3u8, 5), 243);
+    /// assert_eq!(Integer::saturating_pow(&3u8, 6), 255);
+    /// assert_eq!(Integer::saturating_pow(&-3i8, 5), -128);
+    /// assert_eq!(Integer::saturating_pow(&-3i8, 6), 127);
+    /// ~~~
+    fn saturating_pow(&self, exp: u128) -> Self
+    where
+        Self: Clone + CheckedMul + Bounded,
+    {
+        match Integer::checked_pow(self, exp) {
+            Some(r) => r,
+            None if *self < Self::zero() && exp & 1 == 1 => Self::min_value(),
+            None => Self::max_value(),
+        }
+    }
+
+    /// Raises `self` to the power of `exp` by squaring, returning the
+    /// wrapped result along with whether it overflowed.
+    ///
+    /// # Examples
+    ///
+    /// ~~~
+    /// use num_integer::Integer;
+    ///
+    /// assert_eq!(Integer::overflowing_pow(&3u8, 5), (243, false));
+    /// assert_eq!(Integer::overflowing_pow(&3u8, 6), (217, true));
+    /// assert_eq!(Integer::overflowing_pow(&-2i8, 8), (0, true));
+    /// assert_eq!(Integer::overflowing_pow(&3u8, 1 << 100), (1, true));
+    /// ~~~
+    fn overflowing_pow(&self, exp: u128) -> (Self, bool)
+    where
+        Self: Clone + CheckedMul + WrappingMul,
+    {
+        pow_by_squaring(self, exp, |a, b| match a.checked_mul(b) {
+            Some(x) => (x, false),
+            None => (a.wrapping_mul(b), true),
+        })
+    }
+
     /// Decrements self by one.
     ///
     /// # Examples
@@ -433,6 +678,49 @@ pub fn gcd_lcm<T: Integer>(x: T, y: T) -> (T, T) {
     x.gcd_lcm(&y)
 }
 
+/// Raises `base` to the power of `exp` by squaring with the given `mul`,
+/// which also reports whether each product overflowed.
+///
+/// The final squaring is skipped, so every intermediate value is a factor of
+/// the result, and any overflow along the way means the result overflows too.
+fn pow_by_squaring<T, F>(base: &T, mut exp: u128, mul: F) -> (T, bool)
+where
+    T: Integer + Clone,
+    F: Fn(&T, &T) -> (T, bool),
+{
+    if exp == 0 {
+        return (T::one(), false);
+    }
+    if base.is_zero() || base.is_one() {
+        return (base.clone(), false);
+    }
+    if T::one().is_multiple_of(base) {
+        // The base is -1.
+        return if exp & 1 == 1 {
+            (base.clone(), false)
+        } else {
+            (T::one(), false)
+        };
+    }
+
+    let mut base = base.clone();
+    let mut acc = T::one();
+    let mut overflow = false;
+    while exp > 1 {
+        if exp & 1 == 1 {
+            let (x, o) = mul(&acc, &base);
+            acc = x;
+            overflow |= o;
+        }
+        exp >>= 1;
+        let (x, o) = mul(&base, &base);
+        base = x;
+        overflow |= o;
+    }
+    let (x, o) = mul(&acc, &base);
+    (x, overflow | o)
+}
+
 macro_rules! impl_integer_for_isize {
     ($T:ty, $test_mod:ident) => {
         impl Integer for $T {
@@ -612,6 +900,20 @@ macro_rules! impl_integer_for_isize {
 
                 *self - Integer::mod_floor(self, other)
             }
+
+            #[inline]
+            fn checked_pow(&self, exp: u128) -> Option<Self> {
+                // Only 0 and ±1 have powers this wide that don't overflow.
+                if exp < 8 * mem::size_of::<$T>() as u128 {
+                    num_traits::checked_pow(*self, exp as usize)
+                } else {
+                    match *self {
+                        0 | 1 => Some(*self),
+                        -1 => Some(if exp & 1 == 1 { -1 } else { 1 }),
+                        _ => None,
+                    }
+                }
+            }
         }
 
         #[cfg(test)]
@@ -834,6 +1136,96 @@ macro_rules! impl_integer_for_isize {
                     }
                 }
             }
+
+            #[test]
+            fn test_is_power_of() {
+                assert_eq!((1 as $T).is_power_of(&0), Some(0));
+                assert_eq!((0 as $T).is_power_of(&0), Some(1));
+                assert_eq!((0 as $T).is_power_of(&3), None);
+                assert_eq!((1 as $T).is_power_of(&-1), Some(0));
+                assert_eq!((-1 as $T).is_power_of(&-1), Some(1));
+                assert_eq!((-1 as $T).is_power_of(&1), None);
+                assert_eq!((-27 as $T).is_power_of(&-3), Some(3));
+                assert_eq!((81 as $T).is_power_of(&-3), Some(4));
+                assert_eq!((-81 as $T).is_power_of(&-3), None);
+                assert_eq!((-27 as $T).is_power_of(&3), None);
+
+                let min = <$T>::min_value();
+                let bits = 8 * mem::size_of::<$T>() as u32;
+                assert_eq!(min.is_power_of(&-2), Some(bits - 1));
+                assert_eq!(min.is_power_of(&2), None);
+                assert_eq!(min.is_power_of(&min), Some(1));
+                assert_eq!(min.is_power_of(&-1), None);
+                assert_eq!((-1 as $T).is_power_of(&min), None);
+            }
+
+            #[test]
+            fn test_next_prev_power_of() {
+                let max = <$T>::max_value();
+                let top = max / 2 + 1;
+                assert_eq!(max.prev_power_of(&2), Some(top));
+                assert_eq!(top.next_power_of(&2), Some(top));
+                assert_eq!((top + 1).next_power_of(&2), None);
+                assert_eq!(max.prev_power_of(&max), Some(max));
+                assert_eq!(max.next_power_of(&max), Some(max));
+                assert_eq!((-5 as $T).next_power_of(&3), Some(1));
+                assert_eq!((-5 as $T).prev_power_of(&3), None);
+                assert_eq!(<$T>::min_value().prev_power_of(&3), None);
+
+                for x in 1..=max.min(120) {
+                    for base in 2..10 {
+                        let (prev, next) = (x.prev_power_of(&base), x.next_power_of(&base));
+                        let prev = prev.unwrap();
+                        assert!(prev <= x && x / base < prev);
+                        assert!(prev.is_power_of(&base).is_some());
+                        if let Some(next) = next {
+                            assert!(next >= x && (next == 1 || next / base < x));
+                            assert!(next.is_power_of(&base).is_some());
+                        }
+                    }
+                }
+            }
+
+            #[test]
+            fn test_pow() {
+                let (min, max) = (<$T>::min_value(), <$T>::max_value());
+                let extra = [min, min + 1, max / 3, max - 1, max];
+                let bases = (-10..=10).chain(extra.iter().cloned());
+                for base in bases {
+                    // Compare with naive repeated multiplication.
+                    let (mut checked, mut wrapped) = (Some(1 as $T), 1 as $T);
+                    for exp in 0..140u128 {
+                        let overflowed = checked.is_none();
+                        assert_eq!(Integer::checked_pow(&base, exp), checked);
+                        assert_eq!(Integer::overflowing_pow(&base, exp), (wrapped, overflowed));
+                        let saturated = match checked {
+                            Some(x) => x,
+                            None if base < 0 && exp % 2 == 1 => min,
+                            None => max,
+                        };
+                        assert_eq!(Integer::saturating_pow(&base, exp), saturated);
+
+                        checked = checked.and_then(|x| x.checked_mul(base));
+                        wrapped = wrapped.wrapping_mul(base);
+                    }
+                }
+
+                let huge = u128::max_value();
+                assert_eq!(Integer::checked_pow(&(-1 as $T), huge), Some(-1));
+                assert_eq!(Integer::checked_pow(&(-1 as $T), huge - 1), Some(1));
+                assert_eq!(Integer::checked_pow(&(0 as $T), huge), Some(0));
+                assert_eq!(Integer::checked_pow(&(2 as $T), huge), None);
+                assert_eq!(Integer::saturating_pow(&(-2 as $T), huge), min);
+                assert_eq!(Integer::overflowing_pow(&(-2 as $T), huge), (0, true));
+                assert_eq!(Integer::overflowing_pow(&(-1 as $T), huge), (-1, false));
+                assert_eq!(Integer::overflowing_pow(&(3 as $T), 1 << 126), (1, true));
+            }
+
+            #[test]
+            #[should_panic]
+            fn test_next_power_of_negative_base() {
+                (5 as $T).next_power_of(&-2);
+            }
         }
     };
 }
@@ -950,6 +1342,18 @@ macro_rules! impl_integer_for_usize {
             fn div_rem(&self, other: &Self) -> (Self, Self) {
                 (*self / *other, *self % *other)
             }
+
+            #[inline]
+            fn checked_pow(&self, exp: u128) -> Option<Self> {
+                // Only 0 and 1 have powers this wide that don't overflow.
+                if exp < 8 * mem::size_of::<$T>() as u128 {
+                    num_traits::checked_pow(*self, exp as usize)
+                } else if *self <= 1 {
+                    Some(*self)
+                } else {
+                    None
+                }
+            }
         }
 
         #[cfg(test)]
@@ -1045,6 +1449,93 @@ macro_rules! impl_integer_for_usize {
                 assert_eq!((3 as $T).is_odd(), true);
                 assert_eq!((4 as $T).is_odd(), false);
             }
+
+            #[test]
+            fn test_is_power_of() {
+                assert_eq!((1 as $T).is_power_of(&0), Some(0));
+                assert_eq!((0 as $T).is_power_of(&0), Some(1));
+                assert_eq!((0 as $T).is_power_of(&3), None);
+                assert_eq!((1 as $T).is_power_of(&1), Some(0));
+                assert_eq!((5 as $T).is_power_of(&1), None);
+                assert_eq!((5 as $T).is_power_of(&5), Some(1));
+                assert_eq!((125 as $T).is_power_of(&5), Some(3));
+                assert_eq!((124 as $T).is_power_of(&5), None);
+                assert_eq!((250 as $T).is_power_of(&5), None);
+
+                let max = <$T>::max_value();
+                let bits = 8 * mem::size_of::<$T>() as u32;
+                assert_eq!((max / 2 + 1).is_power_of(&2), Some(bits - 1));
+                assert_eq!(max.is_power_of(&2), None);
+                assert_eq!(max.is_power_of(&max), Some(1));
+                assert_eq!((max / 3 * 3).is_power_of(&3), None);
+            }
+
+            #[test]
+            fn test_next_prev_power_of() {
+                let max = <$T>::max_value();
+                let top = max / 2 + 1;
+                assert_eq!(max.prev_power_of(&2), Some(top));
+                assert_eq!(top.next_power_of(&2), Some(top));
+                assert_eq!((top + 1).next_power_of(&2), None);
+                assert_eq!(max.prev_power_of(&max), Some(max));
+                assert_eq!(max.next_power_of(&max), Some(max));
+                assert_eq!((0 as $T).next_power_of(&3), Some(1));
+                assert_eq!((0 as $T).prev_power_of(&3), None);
+
+                for x in 1..=max.min(120) {
+                    for base in 2..10 {
+                        let (prev, next) = (x.prev_power_of(&base), x.next_power_of(&base));
+                        let prev = prev.unwrap();
+                        assert!(prev <= x && x / base < prev);
+                        assert!(prev.is_power_of(&base).is_some());
+                        if let Some(next) = next {
+                            assert!(next >= x && (next == 1 || next / base < x));
+                            assert!(next.is_power_of(&base).is_some());
+                        }
+                    }
+                }
+            }
+
+            #[test]
+            fn test_pow() {
+                let max = <$T>::max_value();
+                let extra = [max / 3, max - 1, max];
+                let bases = (0..=10).chain(extra.iter().cloned());
+                for base in bases {
+                    // Compare with naive repeated multiplication.
+                    let (mut checked, mut wrapped) = (Some(1 as $T), 1 as $T);
+                    for exp in 0..140u128 {
+                        let overflowed = checked.is_none();
+                        assert_eq!(Integer::checked_pow(&base, exp), checked);
+                        assert_eq!(Integer::overflowing_pow(&base, exp), (wrapped, overflowed));
+                        assert_eq!(Integer::saturating_pow(&base, exp), checked.unwrap_or(max));
+
+                        checked = checked.and_then(|x| x.checked_mul(base));
+                        wrapped = wrapped.wrapping_mul(base);
+                    }
+                }
+
+                let huge = u128::max_value();
+                assert_eq!(Integer::checked_pow(&(1 as $T), huge), Some(1));
+                assert_eq!(Integer::checked_pow(&(0 as $T), huge), Some(0));
+                assert_eq!(Integer::checked_pow(&(2 as $T), huge), None);
+                assert_eq!(Integer::saturating_pow(&(2 as $T), huge), max);
+                assert_eq!(Integer::overflowing_pow(&(2 as $T), huge), (0, true));
+                assert_eq!(Integer::overflowing_pow(&max, huge), (max, true));
+                assert_eq!(Integer::overflowing_pow(&(3 as $T), 1 << 126), (1, true));
+            }
+
+            #[test]
+            #[should_panic]
+            fn test_next_power_of_base_one() {
+                (5 as $T).next_power_of(&1);
+            }
+
+            #[test]
+            #[should_panic]
+            fn test_prev_power_of_base_one() {
+                (5 as $T).prev_power_of(&1);
+            }
         }
     };
 }
@@ -1127,6 +1618,15 @@ fn multiply_and_divide<T: Integer + Clone>(r: T, a: T, b: T) -> T {
     r / g.clone() * (a / (b / g))
 }
 
+/// Calculate r * a / b like `multiply_and_divide`, or `None` if the result
+/// overflows.
+///
+/// Assumes that b divides r * a evenly.
+fn checked_multiply_and_divide<T: Integer + Clone + CheckedMul>(r: T, a: T, b: T) -> Option<T> {
+    let g = gcd(r.clone(), b.clone());
+    (r / g.clone()).checked_mul(&(a / (b / g)))
+}
+
 /// Calculate the binomial coefficient.
 ///
 /// Note that this might overflow, depending on `T`. For the primitive integer
@@ -1144,10 +1644,14 @@ fn multiply_and_divide<T: Integer + Clone>(r: T, a: T, b: T) -> T {
 /// u64  | 67
 /// i64  | 66
 ///
-/// For larger n, consider using a bigint type for `T`.
+/// For larger n, consider using [`checked_binomial`](fn.checked_binomial.html)
+/// or a bigint type for `T`, or [`binomial_mod`](fn.binomial_mod.html) if
+/// only the value modulo some `m` is needed.
+///
+/// If `k` is negative or greater than `n`, this is zero.
 pub fn binomial<T: Integer + Clone>(mut n: T, k: T) -> T {
     // See http://blog.plover.com/math/choose.html for the idea.
-    if k > n {
+    if k < T::zero() || k > n {
         return T::zero();
     }
     if k > n.clone() - k.clone() {
@@ -1167,10 +1671,16 @@ pub fn binomial<T: Integer + Clone>(mut n: T, k: T) -> T {
 }
 
 /// Calculate the multinomial coefficient.
-pub fn multinomial<T: Integer + Clone>(k: &[T]) -> T
+///
+/// If any part of `k` is negative, this is zero.
+pub fn multinomial<T>(k: &[T]) -> T
 where
+    T: Integer + Clone,
     for<'a> T: Add<&'a T, Output = T>,
 {
+    if k.iter().any(|i| *i < T::zero()) {
+        return T::zero();
+    }
     let mut r = T::one();
     let mut p = T::zero();
     for i in k {
@@ -1180,6 +1690,468 @@ where
     r
 }
 
+/// Calculate the binomial coefficient, or `None` if it overflows `T`.
+///
+/// Each step reduces by a gcd before multiplying, like
+/// [`binomial`](fn.binomial.html), and the intermediate values are smaller
+/// binomial coefficients, so this only returns `None` if the result itself
+/// doesn't fit.
+///
+/// If `k` is negative or greater than `n`, this is `Some(0)`, matching
+/// [`binomial`](fn.binomial.html).
+///
+/// # Examples
+///
+/// ```
+/// use num_integer::checked_binomial;
+///
+/// assert_eq!(checked_binomial(10u8, 5), Some(252));
+/// assert_eq!(checked_binomial(127i8, -1), Some(0));
+/// assert_eq!(checked_binomial(11u8, 5), None);
+/// assert_eq!(checked_binomial(200u8, 1), Some(200));
+/// assert_eq!(checked_binomial(68u64, 34), None);
+/// assert_eq!(checked_binomial(67u64, 33), Some(14_226_520_737_620_288_370));
+/// ```
+pub fn checked_binomial<T>(mut n: T, k: T) -> Option<T>
+where
+    T: Integer + Clone + CheckedMul,
+{
+    if k < T::zero() || k > n {
+        return Some(T::zero());
+    }
+    if k > n.clone() - k.clone() {
+        return checked_binomial(n.clone(), n - k);
+    }
+    let mut r = T::one();
+    let mut d = T::one();
+    loop {
+        if d > k {
+            break;
+        }
+        r = checked_multiply_and_divide(r, n.clone(), d.clone())?;
+        n = n - T::one();
+        d = d + T::one();
+    }
+    Some(r)
+}
+
+/// Calculate the multinomial coefficient, or `None` if it overflows `T`.
+///
+/// If any part of `k` is negative, this is `Some(0)`, matching
+/// [`multinomial`](fn.multinomial.html).
+///
+/// # Examples
+///
+/// ```
+/// use num_integer::checked_multinomial;
+///
+/// assert_eq!(checked_multinomial(&[2u8, 1, 2]), Some(30));
+/// assert_eq!(checked_multinomial(&[3u8, 3, 3]), None);
+/// assert_eq!(checked_multinomial(&[255u8, 0]), Some(1));
+/// assert_eq!(checked_multinomial(&[255u8, 1]), None);
+/// ```
+pub fn checked_multinomial<T>(k: &[T]) -> Option<T>
+where
+    T: Integer + Clone + CheckedAdd + CheckedMul,
+{
+    if k.iter().any(|i| *i < T::zero()) {
+        return Some(T::zero());
+    }
+    let mut r = T::one();
+    let mut p = T::zero();
+    for i in k {
+        p = p.checked_add(i)?;
+        r = r.checked_mul(&checked_binomial(p.clone(), i.clone())?)?;
+    }
+    Some(r)
+}
+
+/// The factorials `0!` through `34!`, which is the largest that fits in `u128`.
+static FACTORIALS: [u128; 35] = [
+    1,
+    1,
+    2,
+    6,
+    24,
+    120,
+    720,
+    5_040,
+    40_320,
+    362_880,
+    3_628_800,
+    39_916_800,
+    479_001_600,
+    6_227_020_800,
+    87_178_291_200,
+    1_307_674_368_000,
+    20_922_789_888_000,
+    355_687_428_096_000,
+    6_402_373_705_728_000,
+    121_645_100_408_832_000,
+    2_432_902_008_176_640_000,
+    51_090_942_171_709_440_000,
+    1_124_000_727_777_607_680_000,
+    25_852_016_738_884_976_640_000,
+    620_448_401_733_239_439_360_000,
+    15_511_210_043_330_985_984_000_000,
+    403_291_461_126_605_635_584_000_000,
+    10_888_869_450_418_352_160_768_000_000,
+    304_888_344_611_713_860_501_504_000_000,
+    8_841_761_993_739_701_954_543_616_000_000,
+    265_252_859_812_191_058_636_308_480_000_000,
+    8_222_838_654_177_922_817_725_562_880_000_000,
+    263_130_836_933_693_530_167_218_012_160_000_000,
+    8_683_317_618_811_886_495_518_194_401_280_000_000,
+    295_232_799_039_604_140_847_618_609_643_520_000_000,
+];
+
+/// Calculate the factorial `n!`, the product of the integers from 1 to `n`.
+///
+/// For `n` less than 2 this is the empty product, 1.
+///
+/// Note that this might overflow, depending on `T`. For the primitive integer
+/// types, the following n are the largest ones possible such that there will
+/// be no overflow:
+///
+/// type | n
+/// -----|---
+/// u8   |  5
+/// i8   |  5
+/// u16  |  8
+/// i16  |  7
+/// u32  | 12
+/// i32  | 12
+/// u64  | 20
+/// i64  | 20
+/// u128 | 34
+/// i128 | 33
+///
+/// For larger n, consider using [`checked_factorial`](fn.checked_factorial.html)
+/// or a bigint type for `T`.
+///
+/// # Examples
+///
+/// ```
+/// use num_integer::factorial;
+///
+/// assert_eq!(factorial(0u32), 1);
+/// assert_eq!(factorial(5u32), 120);
+/// assert_eq!(factorial(20u64), 2_432_902_008_176_640_000);
+/// assert_eq!(factorial(-3i32), 1);
+/// ```
+pub fn factorial<T: Integer + Clone>(n: T) -> T {
+    let mut r = T::one();
+    let mut k = T::one() + T::one();
+    while k <= n {
+        r = r * k.clone();
+        k = k + T::one();
+    }
+    r
+}
+
+/// Calculate the factorial `n!`, or `None` if it overflows `T`.
+///
+/// As with [`factorial`](fn.factorial.html), `n` less than 2 gives the empty
+/// product, so this returns `Some(1)` for zero and negative `n`.
+///
+/// Up to `34!` this is a table lookup and a conversion, so it takes constant
+/// time for the primitive integer types.  Past that it multiplies its way up
+/// for wider types.
+///
+/// # Examples
+///
+/// ```
+/// use num_integer::checked_factorial;
+///
+/// assert_eq!(checked_factorial(5u8), Some(120));
+/// assert_eq!(checked_factorial(6u8), None);
+/// assert_eq!(checked_factorial(0i32), Some(1));
+/// assert_eq!(checked_factorial(-7i32), Some(1));
+/// assert_eq!(checked_factorial(34u128), Some(295_232_799_039_604_140_847_618_609_643_520_000_000));
+/// assert_eq!(checked_factorial(1_000_000u64), None);
+/// ```
+pub fn checked_factorial<T>(n: T) -> Option<T>
+where
+    T: Integer + Clone + CheckedMul + FromPrimitive + ToPrimitive,
+{
+    if n <= T::one() {
+        return Some(T::one());
+    }
+    if let Some(i) = n.to_usize() {
+        if i < FACTORIALS.len() {
+            return T::from_u128(FACTORIALS[i]);
+        }
+    }
+
+    let last = FACTORIALS.len() - 1;
+    let mut r = T::from_u128(FACTORIALS[last])?;
+    let mut k = T::from_usize(last)?;
+    while k < n {
+        k = k + T::one();
+        r = r.checked_mul(&k)?;
+    }
+    Some(r)
+}
+
+/// Calculate the double factorial `n!!`, the product of the integers from 1
+/// to `n` with the same parity as `n`.
+///
+/// For `n` less than 2 this is the empty product, 1.
+///
+/// Note that this might overflow, depending on `T`. For the primitive integer
+/// types, the following n are the largest ones possible such that there will
+/// be no overflow:
+///
+/// type | n
+/// -----|---
+/// u8   |  7
+/// i8   |  7
+/// u16  | 12
+/// i16  | 11
+/// u32  | 20
+/// i32  | 19
+/// u64  | 33
+/// i64  | 33
+/// u128 | 56
+/// i128 | 56
+///
+/// # Examples
+///
+/// ```
+/// use num_integer::double_factorial;
+///
+/// assert_eq!(double_factorial(7), 105);
+/// assert_eq!(double_factorial(8), 384);
+/// assert_eq!(double_factorial(0), 1);
+/// ```
+pub fn double_factorial<T: Integer + Clone>(n: T) -> T {
+    let two = T::one() + T::one();
+    let mut r = T::one();
+    let mut k = n;
+    while k >= two {
+        r = r * k.clone();
+        k = k - two.clone();
+    }
+    r
+}
+
+/// Calculate the primorial `n#`, the product of the primes up to `n`.
+///
+/// Note that this might overflow, depending on `T`. For the primitive integer
+/// types, the following n are the largest ones possible such that there will
+/// be no overflow:
+///
+/// type | n
+/// -----|---
+/// u8   |  10
+/// i8   |   6
+/// u16  |  16
+/// i16  |  16
+/// u32  |  28
+/// i32  |  28
+/// u64  |  52
+/// i64  |  52
+/// u128 | 102
+/// i128 | 100
+///
+/// # Examples
+///
+/// ```
+/// use num_integer::primorial;
+///
+/// assert_eq!(primorial(10), 210);
+/// assert_eq!(primorial(12), 2310);
+/// assert_eq!(primorial(1), 1);
+/// ```
+pub fn primorial<T: Integer + Clone>(n: T) -> T {
+    let mut r = T::one();
+    let mut k = T::one() + T::one();
+    while k <= n {
+        // Trial division, stopping once d² passes k, without computing d².
+        let mut d = T::one() + T::one();
+        while d <= k.clone() / d.clone() && !k.is_multiple_of(&d) {
+            d = d + T::one();
+        }
+        if d > k.clone() / d.clone() {
+            r = r * k.clone();
+        }
+        k = k + T::one();
+    }
+    r
+}
+
+/// Calculate the exponent of the prime `p` in `n!`, by Legendre's formula
+/// `Σ ⌊n / pⁱ⌋`.
+///
+/// The result is only meaningful when `p` is prime.  It's zero for `n`
+/// less than `p`, including any negative `n`.
+///
+/// # Panics
+///
+/// Panics if `p` is less than 2.
+///
+/// # Examples
+///
+/// ```
+/// use num_integer::factorial_valuation;
+///
+/// // 10! = 2⁸·3⁴·5²·7
+/// assert_eq!(factorial_valuation(10, 2), 8);
+/// assert_eq!(factorial_valuation(10, 5), 2);
+/// assert_eq!(factorial_valuation(10, 11), 0);
+/// // 100! ends in 24 zeros.
+/// assert_eq!(factorial_valuation(100u8, 5), 24);
+/// ```
+pub fn factorial_valuation<T: Integer + Clone>(n: T, p: T) -> T {
+    assert!(p > T::one(), "the prime must be at least 2");
+    let mut e = T::zero();
+    let mut q = n;
+    while q >= p {
+        q = q / p.clone();
+        e = e + q.clone();
+    }
+    e
+}
+
+/// Calculate the falling factorial `n·(n-1)·…·(n-k+1)`, the number of ways
+/// to arrange `k` of `n` items in order (`nPk`).
+///
+/// It's the empty product, 1, for `k ≤ 0`, and zero for `0 ≤ n < k`.
+///
+/// Each partial product divides the result, so for `n ≥ k` or `n < 0` this
+/// overflows only if the result does.  A factor of zero is found before
+/// multiplying, so a zero result never overflows along the way.
+///
+/// # Examples
+///
+/// ```
+/// use num_integer::falling_factorial;
+///
+/// assert_eq!(falling_factorial(10, 3), 720);
+/// assert_eq!(falling_factorial(3, 5), 0);
+/// assert_eq!(falling_factorial(-3, 3), -60);
+/// assert_eq!(falling_factorial(255u8, 1), 255);
+/// ```
+pub fn falling_factorial<T: Integer + Clone>(n: T, k: T) -> T {
+    if n >= T::zero() && n < k {
+        return T::zero();
+    }
+    let mut r = T::one();
+    let mut x = n;
+    let mut i = T::zero();
+    while i < k {
+        r = r * x.clone();
+        i = i + T::one();
+        if i < k {
+            x = x - T::one();
+        }
+    }
+    r
+}
+
+/// Calculate the falling factorial `n·(n-1)·…·(n-k+1)`, or `None` if it
+/// overflows `T` -- see [`falling_factorial`](fn.falling_factorial.html).
+///
+/// # Examples
+///
+/// ```
+/// use num_integer::checked_falling_factorial;
+///
+/// assert_eq!(checked_falling_factorial(255u8, 1), Some(255));
+/// assert_eq!(checked_falling_factorial(255u8, 2), None);
+/// assert_eq!(checked_falling_factorial(-128i8, 1), Some(-128));
+/// assert_eq!(checked_falling_factorial(-128i8, 2), None);
+/// assert_eq!(checked_falling_factorial(100u8, 200), Some(0));
+/// ```
+pub fn checked_falling_factorial<T>(n: T, k: T) -> Option<T>
+where
+    T: Integer + Clone + CheckedMul + CheckedSub,
+{
+    if n >= T::zero() && n < k {
+        return Some(T::zero());
+    }
+    let mut r = T::one();
+    let mut x = n;
+    let mut i = T::zero();
+    while i < k {
+        r = r.checked_mul(&x)?;
+        i = i + T::one();
+        if i < k {
+            x = x.checked_sub(&T::one())?;
+        }
+    }
+    Some(r)
+}
+
+/// Calculate the rising factorial `n·(n+1)·…·(n+k-1)`, also written as the
+/// Pochhammer symbol `(n)ₖ`.
+///
+/// It's the empty product, 1, for `k ≤ 0`, and zero for `-k < n ≤ 0`.
+///
+/// Each partial product divides the result, so for `n > 0` or `n ≤ -k` this
+/// overflows only if the result does.  A factor of zero is found before
+/// multiplying, so a zero result never overflows along the way.
+///
+/// # Examples
+///
+/// ```
+/// use num_integer::rising_factorial;
+///
+/// assert_eq!(rising_factorial(8, 3), 720);
+/// assert_eq!(rising_factorial(-2, 5), 0);
+/// assert_eq!(rising_factorial(-5, 3), -60);
+/// assert_eq!(rising_factorial(1, 5), 120);
+/// ```
+pub fn rising_factorial<T: Integer + Clone>(n: T, k: T) -> T {
+    if k > T::zero() && n <= T::zero() && n.clone() + k.clone() > T::zero() {
+        return T::zero();
+    }
+    let mut r = T::one();
+    let mut x = n;
+    let mut i = T::zero();
+    while i < k {
+        r = r * x.clone();
+        i = i + T::one();
+        if i < k {
+            x = x + T::one();
+        }
+    }
+    r
+}
+
+/// Calculate the rising factorial `n·(n+1)·…·(n+k-1)`, or `None` if it
+/// overflows `T` -- see [`rising_factorial`](fn.rising_factorial.html).
+///
+/// # Examples
+///
+/// ```
+/// use num_integer::checked_rising_factorial;
+///
+/// assert_eq!(checked_rising_factorial(15u8, 2), Some(240));
+/// assert_eq!(checked_rising_factorial(16u8, 2), None);
+/// assert_eq!(checked_rising_factorial(127i8, 1), Some(127));
+/// assert_eq!(checked_rising_factorial(-100i8, 101), Some(0));
+/// ```
+pub fn checked_rising_factorial<T>(n: T, k: T) -> Option<T>
+where
+    T: Integer + Clone + CheckedAdd + CheckedMul,
+{
+    if k > T::zero() && n <= T::zero() && n.clone() + k.clone() > T::zero() {
+        return Some(T::zero());
+    }
+    let mut r = T::one();
+    let mut x = n;
+    let mut i = T::zero();
+    while i < k {
+        r = r.checked_mul(&x)?;
+        i = i + T::one();
+        if i < k {
+            x = x.checked_add(&T::one())?;
+        }
+    }
+    Some(r)
+}
+
 #[test]
 fn test_lcm_overflow() {
     macro_rules! check {
@@ -1309,6 +2281,10 @@ fn test_binomial() {
     check!(i64, 14, 4, 1001);
     check!(i64, 0, 0, 1);
     check!(i64, 2, 3, 0);
+
+    assert_eq!(binomial(5i32, -1), 0);
+    assert_eq!(binomial(-128i8, -128), 0);
+    assert_eq!(binomial(i64::max_value(), -3), 0);
 }
 
 #[test]
@@ -1353,6 +2329,7 @@ fn test_multinomial() {
             let k: &[$t] = $k;
             let expected: $t = $r;
             assert_eq!(multinomial(k), expected);
+            assert_eq!(checked_multinomial(k), Some(expected));
         }};
     }
 
@@ -1383,4 +2360,229 @@ fn test_multinomial() {
     check_multinomial!(u64, &[], 1);
     check_multinomial!(u64, &[0], 1);
     check_multinomial!(u64, &[12345], 1);
+
+    check_multinomial!(i8, &[3, -1], 0);
+    check_multinomial!(i8, &[-128, -128], 0);
+    check_multinomial!(i64, &[2, 3, -1], 0);
+}
+
+#[test]
+fn test_checked_binomial() {
+    macro_rules! check {
+        ($t:ty, $limit:expr) => {{
+            // Walk Pascal's triangle in u128, with `None` past its overflow.
+            let mut row = [Some(0u128); 136];
+            row[0] = Some(1);
+            for n in 0..136usize {
+                for k in (1..=n).rev() {
+                    row[k] = match (row[k], row[k - 1]) {
+                        (Some(a), Some(b)) => a.checked_add(b),
+                        _ => None,
+                    };
+                }
+                let max = <$t>::max_value() as u128;
+                let mut fits = true;
+                for k in 0..=n {
+                    let expected = match row[k] {
+                        Some(b) if b <= max => Some(b as $t),
+                        _ => {
+                            fits = false;
+                            None
+                        }
+                    };
+                    if (n as u128) <= max {
+                        assert_eq!(checked_binomial(n as $t, k as $t), expected);
+                    }
+                }
+                assert_eq!(fits, n <= $limit, "row {}", n);
+            }
+        }};
+    }
+
+    // The largest n for which there is no overflow for any k.
+    check!(u8, 10);
+    check!(i8, 9);
+    check!(u16, 18);
+    check!(i16, 17);
+    check!(u32, 34);
+    check!(i32, 33);
+    check!(u64, 67);
+    check!(i64, 66);
+    check!(u128, 131);
+    check!(i128, 130);
+
+    assert_eq!(checked_binomial(3u8, 4), Some(0));
+    assert_eq!(checked_binomial(255u8, 255), Some(1));
+    assert_eq!(checked_binomial(255u8, 254), Some(255));
+    assert_eq!(checked_binomial(255u8, 2), None);
+    assert_eq!(checked_binomial(127i8, -1), Some(0));
+    assert_eq!(checked_binomial(-128i8, -128), Some(0));
+    assert_eq!(checked_binomial(5i32, i32::min_value()), Some(0));
+    assert_eq!(checked_binomial(i64::max_value(), -3), Some(0));
+    assert_eq!(checked_binomial(132u128, 66), None);
+    assert_eq!(checked_binomial(131i128, 65), None);
+    assert_eq!(
+        checked_binomial(130i128, 65),
+        Some(95_067_625_827_960_698_145_584_333_020_095_113_100)
+    );
+}
+
+#[test]
+fn test_checked_multinomial() {
+    assert_eq!(checked_multinomial::<u8>(&[]), Some(1));
+    assert_eq!(checked_multinomial(&[5u8, 5]), Some(252));
+    assert_eq!(checked_multinomial(&[5u8, 6]), None);
+    assert_eq!(checked_multinomial(&[1u8, 1, 1, 1, 1]), Some(120));
+    assert_eq!(checked_multinomial(&[1u8, 1, 1, 1, 1, 1]), None);
+    assert_eq!(checked_multinomial(&[0u8, 255, 0]), Some(1));
+    assert_eq!(checked_multinomial(&[127i8, 1]), None);
+    assert_eq!(checked_multinomial(&[126i8, 1]), Some(127));
+    assert_eq!(checked_multinomial(&[127i8, -1]), Some(0));
+    assert_eq!(checked_multinomial(&[-128i8, -128]), Some(0));
+    assert_eq!(checked_multinomial(&[2i8, 3, -1]), Some(0));
+    assert_eq!(
+        checked_multinomial(&[10u64, 10, 10]),
+        Some(5_550_996_791_340)
+    );
+    assert_eq!(checked_multinomial(&[20u64, 20, 20]), None);
+}
+
+#[test]
+fn test_factorial() {
+    macro_rules! check {
+        ($t:ty, $factorial:expr, $double:expr, $primorial:expr) => {{
+            // Every n up to the limit matches u128 arithmetic, and the next overflows.
+            let mut f = 1u128;
+            for n in 0..=$factorial {
+                f *= core::cmp::max(n, 1) as u128;
+                assert_eq!(factorial(n as $t) as u128, f);
+                assert_eq!(checked_factorial(n as $t), Some(f as $t));
+            }
+            let next = ($factorial + 1) as $t;
+            assert_eq!(checked_factorial(next), None);
+            assert_eq!(checked_factorial(<$t>::max_value()), None);
+
+            let double = |n: u32| {
+                (1..=n)
+                    .rev()
+                    .step_by(2)
+                    .try_fold(1u128, |r, k| r.checked_mul(u128::from(k)))
+            };
+            assert_eq!(
+                Some(double_factorial($double as $t) as u128),
+                double($double)
+            );
+            assert!(double($double + 1).map_or(true, |d| d > <$t>::max_value() as u128));
+
+            let primes = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53];
+            let prim = |n: u32| {
+                primes
+                    .iter()
+                    .filter(move |&&p| p <= n)
+                    .map(|&p| u128::from(p))
+            };
+            if $primorial < 53 {
+                assert_eq!(
+                    primorial($primorial as $t) as u128,
+                    prim($primorial).product()
+                );
+                let next = primes.iter().find(|&&p| p > $primorial).unwrap();
+                assert!(prim(*next).product::<u128>() > <$t>::max_value() as u128);
+            }
+        }};
+    }
+
+    check!(u8, 5, 7, 10);
+    check!(i8, 5, 7, 6);
+    check!(u16, 8, 12, 16);
+    check!(i16, 7, 11, 16);
+    check!(u32, 12, 20, 28);
+    check!(i32, 12, 19, 28);
+    check!(u64, 20, 33, 52);
+    check!(i64, 20, 33, 52);
+    check!(u128, 34, 56, 102);
+    check!(i128, 33, 56, 100);
+
+    assert_eq!(factorial(-5), 1);
+    assert_eq!(checked_factorial(-5), Some(1));
+    assert_eq!(double_factorial(-1), 1);
+    assert_eq!(primorial(0u8), 1);
+    assert_eq!(primorial(102u128), primorial(100u128) * 101);
+    assert_eq!(checked_factorial(i128::max_value()), None);
+}
+
+#[test]
+fn test_factorial_valuation() {
+    for n in 0..=34u32 {
+        let f = checked_factorial(u128::from(n)).unwrap();
+        for &p in &[2u32, 3, 5, 7, 11, 13, 31, 37] {
+            let mut e = 0;
+            let mut x = f;
+            while x % u128::from(p) == 0 {
+                x /= u128::from(p);
+                e += 1;
+            }
+            assert_eq!(factorial_valuation(n, p), e);
+        }
+    }
+    assert_eq!(factorial_valuation(-10, 2), 0);
+    assert_eq!(
+        factorial_valuation(u64::max_value(), 2),
+        u64::max_value() - 64
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_factorial_valuation_one() {
+    factorial_valuation(10, 1);
+}
+
+#[test]
+fn test_rising_falling_factorial() {
+    macro_rules! check {
+        ($t:ty) => {{
+            let (min, max) = (<$t>::min_value() as i128, <$t>::max_value() as i128);
+            for n in min..=max {
+                for k in core::cmp::max(min, -2)..=20 {
+                    let falling = (0..k).try_fold(1i128, |r, i| r.checked_mul(n - i));
+                    let rising = (0..k).try_fold(1i128, |r, i| r.checked_mul(n + i));
+                    let fits = |x: Option<i128>| match x {
+                        Some(x) if min <= x && x <= max => Some(x as $t),
+                        _ => None,
+                    };
+
+                    let (n, k) = (n as $t, k as $t);
+                    assert_eq!(checked_falling_factorial(n, k), fits(falling));
+                    assert_eq!(checked_rising_factorial(n, k), fits(rising));
+                    if let Some(f) = fits(falling) {
+                        assert_eq!(falling_factorial(n, k), f);
+                    }
+                    if let Some(r) = fits(rising) {
+                        assert_eq!(rising_factorial(n, k), r);
+                    }
+                }
+            }
+        }};
+    }
+
+    check!(u8);
+    check!(i8);
+    check!(i16);
+
+    for n in 0..=20u64 {
+        for k in 0..=n {
+            let permutations = binomial(n, k) * factorial(k);
+            assert_eq!(falling_factorial(n, k), permutations);
+            assert_eq!(rising_factorial(n - k + 1, k), permutations);
+        }
+    }
+    assert_eq!(checked_falling_factorial(34u128, 34), checked_factorial(34));
+    assert_eq!(checked_rising_factorial(-5, i64::max_value()), Some(0));
+    assert_eq!(
+        checked_rising_factorial(i64::min_value(), i64::max_value()),
+        None
+    );
+    assert_eq!(checked_rising_factorial(i64::max_value(), 2), None);
+    assert_eq!(checked_falling_factorial(i64::min_value(), 2), None);
 }
@@ -0,0 +1,327 @@
+use crate::Integer;
+use core::mem;
+
+/// Provides methods to compute an integer's logarithms, rounded to integers.
+///
+/// Since Rust 1.67, the primitive integers have inherent methods named
+/// `ilog2`, `ilog10`, `ilog` and `checked_*` with the same results, and those
+/// take precedence in method-call syntax.  The `ilog` methods there take
+/// `base` by value, so call this trait's versions by path, as in
+/// `Logarithms::ilog(&x, &base)`, for code that builds on any compiler.
+pub trait Logarithms: Integer {
+    /// Returns the base 2 logarithm of an integer, rounded down -- `⌊log₂ x⌋`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero or negative:
+    ///
+    /// ```should_panic
+    /// # use num_integer::Logarithms;
+    /// println!("no logarithm... {}", Logarithms::ilog2(&0u32));
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_integer::Logarithms;
+    ///
+    /// assert_eq!(Logarithms::ilog2(&1u8), 0);
+    /// assert_eq!(Logarithms::ilog2(&255u8), 7);
+    /// assert_eq!(Logarithms::ilog2(&1000i32), 9);
+    /// assert_eq!(Logarithms::ilog2(&std::u128::MAX), 127);
+    /// ```
+    #[inline]
+    fn ilog2(&self) -> u32 {
+        match self.checked_ilog2() {
+            Some(log) => log,
+            None => panic!("argument of integer logarithm must be positive"),
+        }
+    }
+
+    /// Returns the base 10 logarithm of an integer, rounded down -- `⌊log₁₀ x⌋`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero or negative:
+    ///
+    /// ```should_panic
+    /// # use num_integer::Logarithms;
+    /// println!("no logarithm... {}", Logarithms::ilog10(&-10i32));
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_integer::Logarithms;
+    ///
+    /// assert_eq!(Logarithms::ilog10(&9u8), 0);
+    /// assert_eq!(Logarithms::ilog10(&10u8), 1);
+    /// assert_eq!(Logarithms::ilog10(&999_999i64), 5);
+    /// assert_eq!(Logarithms::ilog10(&std::u128::MAX), 38);
+    /// ```
+    #[inline]
+    fn ilog10(&self) -> u32 {
+        match self.checked_ilog10() {
+            Some(log) => log,
+            None => panic!("argument of integer logarithm must be positive"),
+        }
+    }
+
+    /// Returns the logarithm of an integer in the given `base`, rounded down
+    /// -- `⌊log_b x⌋`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero or negative, or if `base` is less than 2:
+    ///
+    /// ```should_panic
+    /// # use num_integer::Logarithms;
+    /// println!("no logarithm... {}", Logarithms::ilog(&100u32, &1));
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_integer::Logarithms;
+    ///
+    /// assert_eq!(Logarithms::ilog(&80u8, &3), 3);
+    /// assert_eq!(Logarithms::ilog(&81u8, &3), 4);
+    /// assert_eq!(Logarithms::ilog(&5i16, &6), 0);
+    /// assert_eq!(Logarithms::ilog(&std::u64::MAX, &std::u64::MAX), 1);
+    /// ```
+    #[inline]
+    fn ilog(&self, base: &Self) -> u32 {
+        assert!(
+            *base > Self::one(),
+            "base of integer logarithm must be at least 2"
+        );
+        match self.checked_ilog(base) {
+            Some(log) => log,
+            None => panic!("argument of integer logarithm must be positive"),
+        }
+    }
+
+    /// Returns the base 2 logarithm of an integer, rounded down, or `None` if
+    /// `self` is zero or negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_integer::Logarithms;
+    ///
+    /// assert_eq!(Logarithms::checked_ilog2(&64u16), Some(6));
+    /// assert_eq!(Logarithms::checked_ilog2(&0u16), None);
+    /// assert_eq!(Logarithms::checked_ilog2(&-64i16), None);
+    /// ```
+    fn checked_ilog2(&self) -> Option<u32>;
+
+    /// Returns the base 10 logarithm of an integer, rounded down, or `None` if
+    /// `self` is zero or negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_integer::Logarithms;
+    ///
+    /// assert_eq!(Logarithms::checked_ilog10(&1000u16), Some(3));
+    /// assert_eq!(Logarithms::checked_ilog10(&0u16), None);
+    /// assert_eq!(Logarithms::checked_ilog10(&-1000i16), None);
+    /// ```
+    fn checked_ilog10(&self) -> Option<u32>;
+
+    /// Returns the logarithm of an integer in the given `base`, rounded down,
+    /// or `None` if `self` is zero or negative, or if `base` is less than 2.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_integer::Logarithms;
+    ///
+    /// assert_eq!(Logarithms::checked_ilog(&100u32, &7), Some(2));
+    /// assert_eq!(Logarithms::checked_ilog(&100u32, &1), None);
+    /// assert_eq!(Logarithms::checked_ilog(&100i32, &-7), None);
+    /// assert_eq!(Logarithms::checked_ilog(&0u32, &7), None);
+    /// ```
+    fn checked_ilog(&self, base: &Self) -> Option<u32>;
+
+    /// Returns the base 2 logarithm of an integer, rounded up -- `⌈log₂ x⌉`
+    ///
+    /// This is the smallest `k` such that `x ≤ 2ᵏ`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero or negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_integer::Logarithms;
+    ///
+    /// assert_eq!(1u8.ilog2_ceil(), 0);
+    /// assert_eq!(64u8.ilog2_ceil(), 6);
+    /// assert_eq!(65u8.ilog2_ceil(), 7);
+    /// assert_eq!(std::u8::MAX.ilog2_ceil(), 8);
+    /// ```
+    #[inline]
+    fn ilog2_ceil(&self) -> u32 {
+        let two = Self::one() + Self::one();
+        Logarithms::ilog_ceil(self, &two)
+    }
+
+    /// Returns the logarithm of an integer in the given `base`, rounded up
+    /// -- `⌈log_b x⌉`
+    ///
+    /// This is the smallest `k` such that `x ≤ bᵏ`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero or negative, or if `base` is less than 2.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_integer::Logarithms;
+    ///
+    /// assert_eq!(1u8.ilog_ceil(&10), 0);
+    /// assert_eq!(100u8.ilog_ceil(&10), 2);
+    /// assert_eq!(101u8.ilog_ceil(&10), 3);
+    /// assert_eq!(std::i64::MAX.ilog_ceil(&2), 63);
+    /// ```
+    fn ilog_ceil(&self, base: &Self) -> u32;
+}
+
+#[inline]
+fn bits<T>() -> u32 {
+    8 * mem::size_of::<T>() as u32
+}
+
+/// Returns `⌊log₁₀ x⌋` for `x > 0`.
+fn log10(x: u128) -> u32 {
+    // 1233/4096 is just under log₁₀ 2, close enough that `t` is `⌊log₁₀ 2ᵏ⌋`
+    // for every bit length `k ≤ 128`.  Since `2ᵏ⁻¹ ≤ x < 2ᵏ`, the logarithm
+    // of `x` is either that or one less.
+    let len = 128 - x.leading_zeros();
+    let t = (len * 1233) >> 12;
+    if x < 10u128.pow(t) {
+        t - 1
+    } else {
+        t
+    }
+}
+
+macro_rules! signed_logarithms {
+    ($T:ty, $U:ty) => {
+        impl Logarithms for $T {
+            #[inline]
+            fn checked_ilog2(&self) -> Option<u32> {
+                if *self > 0 {
+                    Logarithms::checked_ilog2(&(*self as $U))
+                } else {
+                    None
+                }
+            }
+
+            #[inline]
+            fn checked_ilog10(&self) -> Option<u32> {
+                if *self > 0 {
+                    Logarithms::checked_ilog10(&(*self as $U))
+                } else {
+                    None
+                }
+            }
+
+            #[inline]
+            fn checked_ilog(&self, base: &Self) -> Option<u32> {
+                if *self > 0 && *base > 1 {
+                    Logarithms::checked_ilog(&(*self as $U), &(*base as $U))
+                } else {
+                    None
+                }
+            }
+
+            #[inline]
+            fn ilog_ceil(&self, base: &Self) -> u32 {
+                assert!(*base > 1, "base of integer logarithm must be at least 2");
+                assert!(*self > 0, "argument of integer logarithm must be positive");
+                Logarithms::ilog_ceil(&(*self as $U), &(*base as $U))
+            }
+        }
+    };
+}
+
+signed_logarithms!(i8, u8);
+signed_logarithms!(i16, u16);
+signed_logarithms!(i32, u32);
+signed_logarithms!(i64, u64);
+signed_logarithms!(i128, u128);
+signed_logarithms!(isize, usize);
+
+macro_rules! unsigned_logarithms {
+    ($T:ty) => {
+        impl Logarithms for $T {
+            #[inline]
+            fn checked_ilog2(&self) -> Option<u32> {
+                if *self > 0 {
+                    Some(bits::<$T>() - 1 - self.leading_zeros())
+                } else {
+                    None
+                }
+            }
+
+            #[inline]
+            fn checked_ilog10(&self) -> Option<u32> {
+                if *self > 0 {
+                    Some(log10(*self as u128))
+                } else {
+                    None
+                }
+            }
+
+            fn checked_ilog(&self, base: &Self) -> Option<u32> {
+                let (mut x, b) = (*self, *base);
+                if x == 0 || b < 2 {
+                    return None;
+                }
+                if b.is_power_of_two() {
+                    let log = bits::<$T>() - 1 - x.leading_zeros();
+                    return Some(log / b.trailing_zeros());
+                }
+                if b == 10 {
+                    return Some(log10(x as u128));
+                }
+                let mut log = 0;
+                while x >= b {
+                    x /= b;
+                    log += 1;
+                }
+                Some(log)
+            }
+
+            #[inline]
+            fn ilog2_ceil(&self) -> u32 {
+                match *self {
+                    0 => panic!("argument of integer logarithm must be positive"),
+                    1 => 0,
+                    x => bits::<$T>() - (x - 1).leading_zeros(),
+                }
+            }
+
+            fn ilog_ceil(&self, base: &Self) -> u32 {
+                let log = Logarithms::ilog(self, base);
+                // `bˡᵒᵍ ≤ x`, so the power can't overflow.
+                if base.pow(log) == *self {
+                    log
+                } else {
+                    log + 1
+                }
+            }
+        }
+    };
+}
+
+unsigned_logarithms!(u8);
+unsigned_logarithms!(u16);
+unsigned_logarithms!(u32);
+unsigned_logarithms!(u64);
+unsigned_logarithms!(u128);
+unsigned_logarithms!(usize);
@@ -0,0 +1,651 @@
+#[cfg(feature = "alloc")]
+use crate::primes::for_each_prime;
+use crate::primes::{factorize, factorize_wide};
+use crate::Integer;
+#[cfg(feature = "alloc")]
+use crate::Roots;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use core::cmp;
+#[cfg(feature = "alloc")]
+use num_traits::{cast, PrimInt};
+
+/// Provides the multiplicative functions of an integer's prime factorization.
+///
+/// This is implemented for all the primitive integers.  Those up to 64 bits
+/// are factored as `u64`, and the 128-bit ones by Pollard's rho in 128-bit
+/// arithmetic, which can take minutes for a product of two 64-bit primes.
+/// The range functions sieve values whose magnitudes fit in a `u64`, and
+/// factor larger ones one at a time.
+///
+/// A negative integer has the same prime factors as its absolute value, so
+/// the functions agree with those of the absolute value, except that the
+/// squarefree core keeps the sign.  Zero is a multiple of every square, and
+/// its functions are zero.
+pub trait Multiplicative: Integer {
+    /// Returns the Möbius function of an integer -- `μ(n)`.
+    ///
+    /// This is `0` if `n` has a squared prime factor, and otherwise `1` or `-1`
+    /// for an even or odd number of prime factors.  By convention, `μ(0) = 0`,
+    /// and `μ(-n) = μ(n)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_integer::Multiplicative;
+    ///
+    /// assert_eq!(1u32.mobius(), 1);
+    /// assert_eq!(30u64.mobius(), -1);
+    /// assert_eq!(35i16.mobius(), 1);
+    /// assert_eq!(12u8.mobius(), 0);
+    /// assert_eq!((-30i32).mobius(), -1);
+    /// assert_eq!(0i64.mobius(), 0);
+    /// ```
+    fn mobius(&self) -> i8;
+
+    /// Returns the Liouville function of an integer -- `λ(n)`.
+    ///
+    /// This is `1` or `-1` for an even or odd number of prime factors, counted
+    /// with multiplicity.  By convention, `λ(0) = 0`, and `λ(-n) = λ(n)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_integer::Multiplicative;
+    ///
+    /// assert_eq!(1u32.liouville(), 1);
+    /// assert_eq!(12u64.liouville(), -1);
+    /// assert_eq!(36i16.liouville(), 1);
+    /// assert_eq!((-12i32).liouville(), -1);
+    /// assert_eq!(0u8.liouville(), 0);
+    /// ```
+    fn liouville(&self) -> i8;
+
+    /// Returns the radical of an integer, the product of its distinct prime
+    /// factors -- `rad(n)`.
+    ///
+    /// This is never negative.  By convention, `rad(0) = 0`, and
+    /// `rad(-n) = rad(n)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_integer::Multiplicative;
+    ///
+    /// assert_eq!(1u32.radical(), 1);
+    /// assert_eq!(360u64.radical(), 30);
+    /// assert_eq!((1u64 << 40).radical(), 2);
+    /// assert_eq!((-360i32).radical(), 30);
+    /// assert_eq!(std::i8::MIN.radical(), 2);
+    /// ```
+    fn radical(&self) -> Self;
+
+    /// Returns `true` if an integer has no squared prime factor.
+    ///
+    /// Zero is a multiple of every square, so it is not squarefree, and `-n`
+    /// is squarefree if `n` is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_integer::Multiplicative;
+    ///
+    /// assert!(1u32.is_squarefree());
+    /// assert!(30u64.is_squarefree());
+    /// assert!(!12u8.is_squarefree());
+    /// assert!((-30i16).is_squarefree());
+    /// assert!(!0i32.is_squarefree());
+    /// ```
+    fn is_squarefree(&self) -> bool;
+
+    /// Returns the squarefree core of an integer, the `c` of least magnitude
+    /// such that `n = c·m²`.
+    ///
+    /// The core has the same sign as `n`.  By convention, the core of `0` is
+    /// `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_integer::Multiplicative;
+    ///
+    /// assert_eq!(1u32.core_squarefree(), 1);
+    /// assert_eq!(12u64.core_squarefree(), 3);
+    /// assert_eq!(360u16.core_squarefree(), 10);
+    /// assert_eq!((1u64 << 40).core_squarefree(), 1);
+    /// assert_eq!((-12i32).core_squarefree(), -3);
+    /// assert_eq!(std::i8::MIN.core_squarefree(), -2);
+    /// ```
+    fn core_squarefree(&self) -> Self;
+}
+
+/// Returns the Möbius function of `n` --
+/// see [Multiplicative::mobius](trait.Multiplicative.html#tymethod.mobius).
+#[inline]
+pub fn mobius<T: Multiplicative>(n: T) -> i8 {
+    n.mobius()
+}
+
+/// Returns the Liouville function of `n` --
+/// see [Multiplicative::liouville](trait.Multiplicative.html#tymethod.liouville).
+#[inline]
+pub fn liouville<T: Multiplicative>(n: T) -> i8 {
+    n.liouville()
+}
+
+/// Returns the radical of `n` --
+/// see [Multiplicative::radical](trait.Multiplicative.html#tymethod.radical).
+#[inline]
+pub fn radical<T: Multiplicative>(n: T) -> T {
+    n.radical()
+}
+
+/// Returns `true` if `n` has no squared prime factor --
+/// see [Multiplicative::is_squarefree](trait.Multiplicative.html#tymethod.is_squarefree).
+#[inline]
+pub fn is_squarefree<T: Multiplicative>(n: T) -> bool {
+    n.is_squarefree()
+}
+
+/// Returns the squarefree core of `n` --
+/// see [Multiplicative::core_squarefree](trait.Multiplicative.html#tymethod.core_squarefree).
+#[inline]
+pub fn core_squarefree<T: Multiplicative>(n: T) -> T {
+    n.core_squarefree()
+}
+
+macro_rules! factored_multiplicative {
+    ($T:ty, $factorize:ident) => {
+        impl Multiplicative for $T {
+            fn mobius(&self) -> i8 {
+                if *self == 0 {
+                    return 0;
+                }
+                let mut mu = 1;
+                for &(_, e) in $factorize(*self).iter() {
+                    if e > 1 {
+                        return 0;
+                    }
+                    mu = -mu;
+                }
+                mu
+            }
+
+            fn liouville(&self) -> i8 {
+                if *self == 0 {
+                    return 0;
+                }
+                let big_omega: u32 = $factorize(*self).iter().map(|&(_, e)| e).sum();
+                if big_omega % 2 == 0 {
+                    1
+                } else {
+                    -1
+                }
+            }
+
+            fn radical(&self) -> Self {
+                if *self == 0 {
+                    return 0;
+                }
+                $factorize(*self).iter().map(|&(p, _)| p).product()
+            }
+
+            fn is_squarefree(&self) -> bool {
+                *self != 0 && $factorize(*self).iter().all(|&(_, e)| e == 1)
+            }
+
+            fn core_squarefree(&self) -> Self {
+                if *self == 0 {
+                    return 0;
+                }
+                $factorize(*self)
+                    .iter()
+                    .filter(|&&(_, e)| e % 2 == 1)
+                    .map(|&(p, _)| p)
+                    .product()
+            }
+        }
+    };
+}
+
+factored_multiplicative!(u64, factorize);
+factored_multiplicative!(u128, factorize_wide);
+
+macro_rules! unsigned_multiplicative {
+    ($T:ty) => {
+        impl Multiplicative for $T {
+            #[inline]
+            fn mobius(&self) -> i8 {
+                (*self as u64).mobius()
+            }
+
+            #[inline]
+            fn liouville(&self) -> i8 {
+                (*self as u64).liouville()
+            }
+
+            #[inline]
+            fn radical(&self) -> Self {
+                (*self as u64).radical() as Self
+            }
+
+            #[inline]
+            fn is_squarefree(&self) -> bool {
+                (*self as u64).is_squarefree()
+            }
+
+            #[inline]
+            fn core_squarefree(&self) -> Self {
+                (*self as u64).core_squarefree() as Self
+            }
+        }
+    };
+}
+
+unsigned_multiplicative!(u8);
+unsigned_multiplicative!(u16);
+unsigned_multiplicative!(u32);
+unsigned_multiplicative!(usize);
+
+macro_rules! signed_multiplicative {
+    ($T:ty, $U:ty) => {
+        impl Multiplicative for $T {
+            #[inline]
+            fn mobius(&self) -> i8 {
+                (self.wrapping_abs() as $U).mobius()
+            }
+
+            #[inline]
+            fn liouville(&self) -> i8 {
+                (self.wrapping_abs() as $U).liouville()
+            }
+
+            #[inline]
+            fn radical(&self) -> Self {
+                // Only `MIN` has no absolute value, and its radical is 2.
+                (self.wrapping_abs() as $U).radical() as Self
+            }
+
+            #[inline]
+            fn is_squarefree(&self) -> bool {
+                (self.wrapping_abs() as $U).is_squarefree()
+            }
+
+            #[inline]
+            fn core_squarefree(&self) -> Self {
+                let core = (self.wrapping_abs() as $U).core_squarefree() as Self;
+                if *self < 0 {
+                    -core
+                } else {
+                    core
+                }
+            }
+        }
+    };
+}
+
+signed_multiplicative!(i8, u8);
+signed_multiplicative!(i16, u16);
+signed_multiplicative!(i32, u32);
+signed_multiplicative!(i64, u64);
+signed_multiplicative!(i128, u128);
+signed_multiplicative!(isize, usize);
+
+/// Calls `f(i, p, e)` for each prime power `pᵉ` exactly dividing `start + i`,
+/// for every nonzero value in `start .. start + len`.
+///
+/// The values are sifted in place by the primes up to the square root of the
+/// end of the range, and whatever remains greater than one is a prime factor.
+#[cfg(feature = "alloc")]
+fn for_each_factor<F>(start: u64, len: usize, mut f: F)
+where
+    F: FnMut(usize, u64, u32),
+{
+    if len == 0 {
+        return;
+    }
+    let end = start
+        .checked_add(len as u64 - 1)
+        .expect("the range overflows u64");
+
+    // Sifting a narrow window at a high offset would spend most of its time
+    // finding the sieving primes, so just factor each value directly.
+    let root = end.sqrt();
+    if (len as u64) < root {
+        for i in 0..len {
+            let n = start + i as u64;
+            if n != 0 {
+                for &(p, e) in &factorize(n) {
+                    f(i, p, e);
+                }
+            }
+        }
+        return;
+    }
+
+    let mut rest: Vec<u64> = (0..len as u64).map(|i| start + i).collect();
+    for_each_prime(2, root, |p| {
+        let mut m = match start % p {
+            0 => start,
+            r => match start.checked_add(p - r) {
+                Some(m) => m,
+                None => return true,
+            },
+        };
+        if m == 0 {
+            m = p;
+        }
+        while m <= end {
+            let i = (m - start) as usize;
+            let mut e = 0;
+            while rest[i] % p == 0 {
+                rest[i] /= p;
+                e += 1;
+            }
+            f(i, p, e);
+            m = match m.checked_add(p) {
+                Some(m) => m,
+                None => break,
+            };
+        }
+        true
+    });
+
+    for (i, &r) in rest.iter().enumerate() {
+        if r > 1 {
+            f(i, r, 1);
+        }
+    }
+}
+
+/// Calls `fill(m, part)` for the parts of `table` holding the negative and
+/// the non-negative values of `start + i`, where `m` is the least magnitude in
+/// the part.  The negative part is filled by increasing magnitude, and then
+/// reversed into place.  Returns the length of the negative part, or `None`,
+/// leaving the table alone, if a magnitude in the range doesn't fit in a
+/// `u64`.
+///
+/// # Panics
+///
+/// Panics if the range overflows `T`.
+#[cfg(feature = "alloc")]
+fn for_each_part<T, U, F>(start: T, table: &mut [U], mut fill: F) -> Option<usize>
+where
+    T: Multiplicative + PrimInt,
+    F: FnMut(u64, &mut [U]),
+{
+    let last = match table.len().checked_sub(1) {
+        Some(last) => last,
+        None => return Some(0),
+    };
+    // The offset may not fit in `T` itself, so add it in 128 bits.
+    let end = match start.to_i128() {
+        Some(start) => start.checked_add(last as i128).and_then(cast::<i128, T>),
+        None => start
+            .to_u128()
+            .and_then(|start| start.checked_add(last as u128))
+            .and_then(cast::<u128, T>),
+    };
+    let end = end.expect("the range overflows its type");
+
+    let max = i128::from(core::u64::MAX);
+    let start = match (start.to_i128(), end.to_i128()) {
+        (Some(start), Some(end)) if -max <= start && end <= max => start,
+        _ => return None,
+    };
+
+    let neg = if start < 0 {
+        cmp::min(-start, table.len() as i128) as usize
+    } else {
+        0
+    };
+    let (negative, rest) = table.split_at_mut(neg);
+    if neg > 0 {
+        fill((-start - (neg as i128 - 1)) as u64, negative);
+        negative.reverse();
+    }
+    fill(cmp::max(start, 0) as u64, rest);
+    Some(neg)
+}
+
+/// Fills `table[i]` with `f(start + i)`, one value at a time.
+#[cfg(feature = "alloc")]
+fn fill_each<T, U, F>(start: T, table: &mut [U], f: F)
+where
+    T: PrimInt,
+    F: Fn(T) -> U,
+{
+    let mut n = start;
+    for (i, x) in table.iter_mut().enumerate() {
+        if i > 0 {
+            n = n + T::one();
+        }
+        *x = f(n);
+    }
+}
+
+/// Fills `table[i]` with `μ(start + i)` --
+/// see [Multiplicative::mobius](trait.Multiplicative.html#tymethod.mobius).
+///
+/// # Panics
+///
+/// Panics if the range overflows `T`.
+///
+/// # Examples
+///
+/// ```
+/// use num_integer::mobius_range;
+///
+/// let mut mu = [0; 11];
+/// mobius_range(0u64, &mut mu);
+/// assert_eq!(mu, [0, 1, -1, -1, 0, -1, 1, -1, 0, 0, 1]);
+///
+/// mobius_range(-5i32, &mut mu);
+/// assert_eq!(mu, [-1, 0, -1, -1, 1, 0, 1, -1, -1, 0, -1]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn mobius_range<T: Multiplicative + PrimInt>(start: T, table: &mut [i8]) {
+    let sifted = for_each_part(start, table, |start, table| {
+        fill_nonzero(start, table, 1, 0);
+        for_each_factor(start, table.len(), |i, _, e| {
+            table[i] = if e > 1 { 0 } else { -table[i] };
+        });
+    });
+    if sifted.is_none() {
+        fill_each(start, table, |n| n.mobius());
+    }
+}
+
+/// Fills `table[i]` with `λ(start + i)` --
+/// see [Multiplicative::liouville](trait.Multiplicative.html#tymethod.liouville).
+///
+/// # Panics
+///
+/// Panics if the range overflows `T`.
+///
+/// # Examples
+///
+/// ```
+/// use num_integer::liouville_range;
+///
+/// let mut lambda = [0; 11];
+/// liouville_range(0u64, &mut lambda);
+/// assert_eq!(lambda, [0, 1, -1, -1, 1, -1, 1, -1, -1, 1, 1]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn liouville_range<T: Multiplicative + PrimInt>(start: T, table: &mut [i8]) {
+    let sifted = for_each_part(start, table, |start, table| {
+        fill_nonzero(start, table, 1, 0);
+        for_each_factor(start, table.len(), |i, _, e| {
+            if e % 2 == 1 {
+                table[i] = -table[i];
+            }
+        });
+    });
+    if sifted.is_none() {
+        fill_each(start, table, |n| n.liouville());
+    }
+}
+
+/// Fills `table[i]` with `rad(start + i)` --
+/// see [Multiplicative::radical](trait.Multiplicative.html#tymethod.radical).
+///
+/// # Panics
+///
+/// Panics if the range overflows `T`.
+///
+/// # Examples
+///
+/// ```
+/// use num_integer::radical_range;
+///
+/// let mut rad = [0; 6];
+/// radical_range(10u64, &mut rad);
+/// assert_eq!(rad, [10, 11, 6, 13, 14, 15]);
+///
+/// let mut rad = [0; 5];
+/// radical_range(-2i8, &mut rad);
+/// assert_eq!(rad, [2, 1, 0, 1, 2]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn radical_range<T: Multiplicative + PrimInt>(start: T, table: &mut [T]) {
+    let sifted = for_each_part(start, table, |start, table| {
+        fill_nonzero(start, table, T::one(), T::zero());
+        for_each_factor(start, table.len(), |i, p, _| {
+            table[i] = table[i] * from_u64(p);
+        });
+    });
+    if sifted.is_none() {
+        fill_each(start, table, |n| n.radical());
+    }
+}
+
+/// Fills `table[i]` with `is_squarefree(start + i)` --
+/// see [Multiplicative::is_squarefree](trait.Multiplicative.html#tymethod.is_squarefree).
+///
+/// Unlike the other range functions, this only has to sift by the squares of
+/// primes, not find every factor.
+///
+/// # Panics
+///
+/// Panics if the range overflows `T`.
+///
+/// # Examples
+///
+/// ```
+/// use num_integer::squarefree_range;
+///
+/// let mut sf = [false; 6];
+/// squarefree_range(0u64, &mut sf);
+/// assert_eq!(sf, [false, true, true, true, false, true]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn squarefree_range<T: Multiplicative + PrimInt>(start: T, table: &mut [bool]) {
+    if for_each_part(start, table, squarefree_part).is_none() {
+        fill_each(start, table, |n| n.is_squarefree());
+    }
+}
+
+/// Fills `table[i]` with `is_squarefree(start + i)`, by sifting with the
+/// squares of primes.
+#[cfg(feature = "alloc")]
+fn squarefree_part(start: u64, table: &mut [bool]) {
+    fill_nonzero(start, table, true, false);
+    if table.is_empty() {
+        return;
+    }
+    let end = start
+        .checked_add(table.len() as u64 - 1)
+        .expect("the range overflows u64");
+
+    let root = end.sqrt();
+    if (table.len() as u64) < root {
+        for (i, x) in table.iter_mut().enumerate() {
+            *x = (start + i as u64).is_squarefree();
+        }
+        return;
+    }
+
+    for_each_prime(2, root, |p| {
+        let p2 = p * p;
+        let mut m = match start % p2 {
+            0 => start,
+            r => match start.checked_add(p2 - r) {
+                Some(m) => m,
+                None => return true,
+            },
+        };
+        while m <= end {
+            table[(m - start) as usize] = false;
+            m = match m.checked_add(p2) {
+                Some(m) => m,
+                None => break,
+            };
+        }
+        true
+    });
+}
+
+/// Fills `table[i]` with the squarefree core of `start + i` --
+/// see [Multiplicative::core_squarefree](trait.Multiplicative.html#tymethod.core_squarefree).
+///
+/// # Panics
+///
+/// Panics if the range overflows `T`.
+///
+/// # Examples
+///
+/// ```
+/// use num_integer::core_squarefree_range;
+///
+/// let mut core = [0; 6];
+/// core_squarefree_range(10u64, &mut core);
+/// assert_eq!(core, [10, 11, 3, 13, 14, 15]);
+///
+/// let mut core = [0; 5];
+/// core_squarefree_range(-12i32, &mut core);
+/// assert_eq!(core, [-3, -11, -10, -1, -2]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn core_squarefree_range<T: Multiplicative + PrimInt>(start: T, table: &mut [T]) {
+    let neg = for_each_part(start, table, |start, table| {
+        fill_nonzero(start, table, T::one(), T::zero());
+        for_each_factor(start, table.len(), |i, p, e| {
+            if e % 2 == 1 {
+                table[i] = table[i] * from_u64(p);
+            }
+        });
+    });
+    match neg {
+        Some(neg) => {
+            for x in &mut table[..neg] {
+                *x = T::zero() - *x;
+            }
+        }
+        None => fill_each(start, table, |n| n.core_squarefree()),
+    }
+}
+
+/// Converts a factor of a value in `T` back to `T`.
+#[cfg(feature = "alloc")]
+#[inline]
+fn from_u64<T: PrimInt>(p: u64) -> T {
+    match cast(p) {
+        Some(p) => p,
+        None => unreachable!(),
+    }
+}
+
+/// Fills the table with `one`, except for `zero` at the position of zero.
+#[cfg(feature = "alloc")]
+fn fill_nonzero<T: Copy>(start: u64, table: &mut [T], one: T, zero: T) {
+    for x in table.iter_mut() {
+        *x = one;
+    }
+    if start == 0 {
+        if let Some(x) = table.first_mut() {
+            *x = zero;
+        }
+    }
+}
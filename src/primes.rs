@@ -0,0 +1,886 @@
+use crate::{Integer, Roots};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::{cmp, fmt};
+
+/// The most distinct prime factors a `u64` can have, as the product of the
+/// first 16 primes already exceeds `u64::MAX`.
+pub(crate) const MAX_FACTORS: usize = 15;
+
+/// The prime factorization of a `u64`, as pairs of distinct primes and their
+/// exponents in increasing order of the primes.
+///
+/// This is a fixed-size value that doesn't need to allocate.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Factorization {
+    factors: [(u64, u32); MAX_FACTORS],
+    len: usize,
+}
+
+impl Factorization {
+    /// Returns the number of distinct prime factors -- `ω(n)`.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if there are no prime factors, which is only the case for `1`.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the prime factors and their exponents as a slice.
+    #[inline]
+    pub fn as_slice(&self) -> &[(u64, u32)] {
+        &self.factors[..self.len]
+    }
+
+    /// Returns an iterator over the prime factors and their exponents.
+    #[inline]
+    pub fn iter(&self) -> core::slice::Iter<'_, (u64, u32)> {
+        self.as_slice().iter()
+    }
+
+    /// Returns the number that was factored.
+    pub fn value(&self) -> u64 {
+        self.iter().fold(1, |n, &(p, e)| n * p.pow(e))
+    }
+
+    /// Multiplies in `p^e`, keeping the primes sorted.
+    fn insert(&mut self, p: u64, e: u32) {
+        let mut i = self.len;
+        while i > 0 && self.factors[i - 1].0 >= p {
+            i -= 1;
+        }
+        if i < self.len && self.factors[i].0 == p {
+            self.factors[i].1 += e;
+        } else {
+            let mut j = self.len;
+            while j > i {
+                self.factors[j] = self.factors[j - 1];
+                j -= 1;
+            }
+            self.factors[i] = (p, e);
+            self.len += 1;
+        }
+    }
+}
+
+impl fmt::Debug for Factorization {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<'a> IntoIterator for &'a Factorization {
+    type Item = &'a (u64, u32);
+    type IntoIter = core::slice::Iter<'a, (u64, u32)>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Returns the prime factorization of `n`.
+///
+/// Small factors are found by trial division, and the rest with Brent's
+/// variant of Pollard's rho algorithm.
+///
+/// # Panics
+///
+/// Panics if `n` is zero.
+///
+/// # Examples
+///
+/// ```
+/// use num_integer::factorize;
+///
+/// assert!(factorize(1).is_empty());
+/// assert_eq!(factorize(360).as_slice(), &[(2, 3), (3, 2), (5, 1)]);
+/// assert_eq!(factorize(600_851_475_143).as_slice(),
+///            &[(71, 1), (839, 1), (1_471, 1), (6_857, 1)]);
+/// assert_eq!(factorize(std::u64::MAX).value(), std::u64::MAX);
+/// ```
+pub fn factorize(n: u64) -> Factorization {
+    assert!(n != 0, "zero has no prime factorization");
+
+    let mut f = Factorization {
+        factors: [(0, 0); MAX_FACTORS],
+        len: 0,
+    };
+
+    let mut n = n;
+    let twos = n.trailing_zeros();
+    if twos > 0 {
+        f.insert(2, twos);
+        n >>= twos;
+    }
+
+    let mut p = 3;
+    while p < 256 && p * p <= n {
+        if n % p == 0 {
+            let mut e = 0;
+            while n % p == 0 {
+                n /= p;
+                e += 1;
+            }
+            f.insert(p, e);
+        }
+        p += 2;
+    }
+
+    if n > 1 {
+        if p * p > n {
+            f.insert(n, 1);
+        } else {
+            factor_large(&mut f, n);
+        }
+    }
+    f
+}
+
+/// Factors `n`, which has no prime factors less than 256.
+fn factor_large(f: &mut Factorization, n: u64) {
+    if is_prime(n) {
+        f.insert(n, 1);
+    } else {
+        let d = pollard_brent(n);
+        factor_large(f, d);
+        factor_large(f, n / d);
+    }
+}
+
+/// Returns a nontrivial factor of the odd composite `n`.
+fn pollard_brent(n: u64) -> u64 {
+    // Multiply this many differences together between each `gcd`.
+    const BATCH: u64 = 128;
+
+    let step =
+        |x: u64, c: u64| ((u128::from(x) * u128::from(x) + u128::from(c)) % u128::from(n)) as u64;
+    let diff = |x: u64, y: u64| if x > y { x - y } else { y - x };
+
+    for c in 1.. {
+        let mut y = 2;
+        let mut x = y;
+        let mut ys = y;
+        let mut q = 1;
+        let mut g = 1;
+        let mut r = 1;
+        while g == 1 {
+            x = y;
+            for _ in 0..r {
+                y = step(y, c);
+            }
+            let mut k = 0;
+            while k < r && g == 1 {
+                ys = y;
+                for _ in 0..cmp::min(BATCH, r - k) {
+                    y = step(y, c);
+                    q = mul_mod(q, diff(x, y), n);
+                }
+                g = q.gcd(&n);
+                k += BATCH;
+            }
+            r *= 2;
+        }
+
+        if g == n {
+            // The batch overshot, so step back through it one at a time.
+            loop {
+                ys = step(ys, c);
+                g = diff(x, ys).gcd(&n);
+                if g != 1 {
+                    break;
+                }
+            }
+        }
+        if g != n {
+            return g;
+        }
+    }
+    unreachable!()
+}
+
+#[inline]
+pub(crate) fn mul_mod(a: u64, b: u64, m: u64) -> u64 {
+    ((u128::from(a) * u128::from(b)) % u128::from(m)) as u64
+}
+
+pub(crate) fn pow_mod(mut base: u64, mut exp: u64, m: u64) -> u64 {
+    let mut acc = 1;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            acc = mul_mod(acc, base, m);
+        }
+        base = mul_mod(base, base, m);
+        exp >>= 1;
+    }
+    acc
+}
+
+/// Returns `true` if `n` is prime.
+///
+/// This is a Miller–Rabin test with the first twelve primes as bases, which is
+/// deterministic for every `u64`.
+///
+/// # Examples
+///
+/// ```
+/// use num_integer::is_prime;
+///
+/// assert!(!is_prime(1));
+/// assert!(is_prime(2));
+/// assert!(is_prime(7_919));
+/// assert!(!is_prime(3_215_031_751)); // a strong pseudoprime to bases 2, 3, 5 and 7
+/// assert!(is_prime(18_446_744_073_709_551_557)); // the largest `u64` prime
+/// ```
+pub fn is_prime(n: u64) -> bool {
+    const BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+    if n < 2 {
+        return false;
+    }
+    for &p in &BASES {
+        if n % p == 0 {
+            return n == p;
+        }
+    }
+    if n < 41 * 41 {
+        return true;
+    }
+
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+    'bases: for &a in &BASES {
+        let mut x = pow_mod(a, d, n);
+        if x == 1 || x == n - 1 {
+            continue;
+        }
+        for _ in 1..s {
+            x = mul_mod(x, x, n);
+            if x == n - 1 {
+                continue 'bases;
+            }
+        }
+        return false;
+    }
+    true
+}
+
+/// The most distinct prime factors a `u128` can have, as the product of the
+/// first 27 primes already exceeds `u128::MAX`.
+const MAX_WIDE_FACTORS: usize = 26;
+
+/// The prime factorization of a `u128`, like `Factorization`, but with the
+/// primes in no particular order.
+pub(crate) struct WideFactorization {
+    factors: [(u128, u32); MAX_WIDE_FACTORS],
+    len: usize,
+}
+
+impl WideFactorization {
+    /// Returns an iterator over the prime factors and their exponents.
+    #[inline]
+    pub(crate) fn iter(&self) -> core::slice::Iter<'_, (u128, u32)> {
+        self.factors[..self.len].iter()
+    }
+
+    /// Multiplies in `p^e`.
+    fn insert(&mut self, p: u128, e: u32) {
+        for f in &mut self.factors[..self.len] {
+            if f.0 == p {
+                f.1 += e;
+                return;
+            }
+        }
+        self.factors[self.len] = (p, e);
+        self.len += 1;
+    }
+}
+
+/// Returns the prime factorization of `n`.
+///
+/// Values that fit in a `u64` are factored by `factorize`, and larger ones
+/// the same way in 128-bit Montgomery arithmetic, but testing primality with
+/// Baillie–PSW, which has no known counterexample.  Each step of Pollard's rho
+/// takes around a hundred nanoseconds, and finding a prime factor `p` takes
+/// about `√p` of them, so a product of two 64-bit primes can take minutes.
+///
+/// # Panics
+///
+/// Panics if `n` is zero.
+pub(crate) fn factorize_wide(n: u128) -> WideFactorization {
+    let mut f = WideFactorization {
+        factors: [(0, 0); MAX_WIDE_FACTORS],
+        len: 0,
+    };
+    if n <= u128::from(core::u64::MAX) {
+        for &(p, e) in &factorize(n as u64) {
+            f.insert(u128::from(p), e);
+        }
+        return f;
+    }
+
+    let mut n = n;
+    let twos = n.trailing_zeros();
+    if twos > 0 {
+        f.insert(2, twos);
+        n >>= twos;
+    }
+
+    let mut p = 3;
+    while p < 256 && p * p <= n {
+        if n % p == 0 {
+            let mut e = 0;
+            while n % p == 0 {
+                n /= p;
+                e += 1;
+            }
+            f.insert(p, e);
+        }
+        p += 2;
+    }
+
+    if n > 1 {
+        if p * p > n {
+            f.insert(n, 1);
+        } else {
+            factor_wide(&mut f, n, 1);
+        }
+    }
+    f
+}
+
+/// Multiplies in the factors of `nᵉ`, where `n` has no prime factors less
+/// than 256.
+fn factor_wide(f: &mut WideFactorization, n: u128, e: u32) {
+    if n <= u128::from(core::u64::MAX) {
+        for &(p, k) in &factorize(n as u64) {
+            f.insert(u128::from(p), k * e);
+        }
+        return;
+    }
+    if is_prime_wide(n) {
+        f.insert(n, e);
+        return;
+    }
+
+    // Pollard's rho takes about `√p` steps to find `p`, even in `p²`, so
+    // take out perfect powers first.  They're at most 256¹⁶, at the 16th power.
+    for k in (2..17).rev() {
+        if let Some(r) = n.exact_nth_root(k) {
+            factor_wide(f, r, e * k);
+            return;
+        }
+    }
+    let d = pollard_brent_wide(n);
+    factor_wide(f, d, e);
+    factor_wide(f, n / d, e);
+}
+
+/// Arithmetic modulo an odd `n` in Montgomery form, as `x·2¹²⁸ mod n`.
+struct Montgomery {
+    n: u128,
+    /// `-n⁻¹ mod 2¹²⁸`
+    inv: u128,
+    /// `2¹²⁸ mod n`, which is 1 in Montgomery form.
+    one: u128,
+    /// `2²⁵⁶ mod n`, which converts into Montgomery form.
+    r2: u128,
+}
+
+impl Montgomery {
+    fn new(n: u128) -> Self {
+        // Each Newton step doubles the correct low bits, from the 3 of `n`.
+        let mut inv = n;
+        for _ in 0..6 {
+            inv = inv.wrapping_mul(2u128.wrapping_sub(n.wrapping_mul(inv)));
+        }
+        let one = (core::u128::MAX % n + 1) % n;
+        let mut r2 = one;
+        for _ in 0..128 {
+            r2 = add_mod_wide(r2, r2, n);
+        }
+        Montgomery {
+            n,
+            inv: inv.wrapping_neg(),
+            one,
+            r2,
+        }
+    }
+
+    #[inline]
+    fn enter(&self, x: u128) -> u128 {
+        self.mul(x % self.n, self.r2)
+    }
+
+    #[inline]
+    fn mul(&self, a: u128, b: u128) -> u128 {
+        let (hi, lo) = mul_wide(a, b);
+        let m = lo.wrapping_mul(self.inv);
+        let (mhi, _) = mul_wide(m, self.n);
+        // The low halves sum to zero modulo 2¹²⁸, carrying unless both are zero.
+        let (t, c1) = hi.overflowing_add(mhi);
+        let (t, c2) = t.overflowing_add(u128::from(lo != 0));
+        if c1 || c2 || t >= self.n {
+            t.wrapping_sub(self.n)
+        } else {
+            t
+        }
+    }
+
+    #[inline]
+    fn add(&self, a: u128, b: u128) -> u128 {
+        add_mod_wide(a, b, self.n)
+    }
+
+    #[inline]
+    fn sub(&self, a: u128, b: u128) -> u128 {
+        if a >= b {
+            a - b
+        } else {
+            a.wrapping_sub(b).wrapping_add(self.n)
+        }
+    }
+
+    /// Divides by two, which is the same in Montgomery form.
+    #[inline]
+    fn half(&self, a: u128) -> u128 {
+        if a % 2 == 0 {
+            a >> 1
+        } else {
+            (a >> 1) + (self.n >> 1) + 1
+        }
+    }
+
+    fn pow(&self, mut base: u128, mut exp: u128) -> u128 {
+        let mut acc = self.one;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                acc = self.mul(acc, base);
+            }
+            base = self.mul(base, base);
+            exp >>= 1;
+        }
+        acc
+    }
+}
+
+#[inline]
+fn add_mod_wide(a: u128, b: u128, n: u128) -> u128 {
+    let (s, c) = a.overflowing_add(b);
+    if c || s >= n {
+        s.wrapping_sub(n)
+    } else {
+        s
+    }
+}
+
+/// Returns the high and low halves of the 256-bit product `a·b`.
+#[inline]
+fn mul_wide(a: u128, b: u128) -> (u128, u128) {
+    const LO: u128 = core::u64::MAX as u128;
+    let (a0, a1) = (u128::from(a as u64), u128::from((a >> 64) as u64));
+    let (b0, b1) = (u128::from(b as u64), u128::from((b >> 64) as u64));
+    let (p00, p01, p10, p11) = (a0 * b0, a0 * b1, a1 * b0, a1 * b1);
+    let mid = (p00 >> 64) + (p01 & LO) + (p10 & LO);
+    let lo = (p00 & LO) | mid << 64;
+    let hi = p11 + (p01 >> 64) + (p10 >> 64) + (mid >> 64);
+    (hi, lo)
+}
+
+/// Returns `true` if the odd `n > u64::MAX` with no prime factors less than
+/// 256 is a probable prime to the Baillie–PSW test: a strong probable prime
+/// to base 2, and a strong Lucas probable prime with Selfridge's parameters.
+fn is_prime_wide(n: u128) -> bool {
+    let m = Montgomery::new(n);
+    let minus_one = n - m.one;
+
+    let s = (n - 1).trailing_zeros();
+    let mut x = m.pow(m.enter(2), (n - 1) >> s);
+    if x != m.one && x != minus_one {
+        let mut i = 1;
+        while x != minus_one {
+            if i == s {
+                return false;
+            }
+            x = m.mul(x, x);
+            i += 1;
+        }
+    }
+
+    // Selfridge's method never finds a `D` for a square.
+    if n.sqrt() * n.sqrt() == n {
+        return false;
+    }
+    let mut d = 5i32;
+    loop {
+        let a = if d > 0 { d as u128 } else { n - (-d) as u128 };
+        match jacobi(a, n) {
+            -1 => break,
+            // `n` has no factors this small, so `(D/n)` is never 0.
+            _ => d = if d > 0 { -d - 2 } else { -d + 2 },
+        }
+    }
+    let q = i64::from(1 - d) / 4;
+    let (dm, qm) = (m.enter(mod_wide(i64::from(d), n)), m.enter(mod_wide(q, n)));
+
+    // `n + 1 = k·2ˢ` with `k` odd, and `(U, V, Q)` are `(Uⱼ, Vⱼ, Qʲ)`, with
+    // `P = 1`, for the leading bits `j` of `k`.
+    let s = (n + 1).trailing_zeros();
+    let k = (n + 1) >> s;
+    let (mut u, mut v, mut qk) = (m.one, m.one, qm);
+    for bit in (0..127 - k.leading_zeros()).rev() {
+        u = m.mul(u, v);
+        v = m.sub(m.mul(v, v), m.add(qk, qk));
+        qk = m.mul(qk, qk);
+        if k >> bit & 1 == 1 {
+            let (u1, v1) = (m.half(m.add(u, v)), m.half(m.add(m.mul(dm, u), v)));
+            u = u1;
+            v = v1;
+            qk = m.mul(qk, qm);
+        }
+    }
+    if u == 0 || v == 0 {
+        return true;
+    }
+    for _ in 1..s {
+        v = m.sub(m.mul(v, v), m.add(qk, qk));
+        qk = m.mul(qk, qk);
+        if v == 0 {
+            return true;
+        }
+    }
+    false
+}
+
+/// Returns `x mod n` for a small signed `x`.
+#[inline]
+fn mod_wide(x: i64, n: u128) -> u128 {
+    if x >= 0 {
+        x as u128
+    } else {
+        n - u128::from(x.wrapping_neg() as u64)
+    }
+}
+
+/// Returns the Jacobi symbol `(a/n)` for an odd `n`.
+fn jacobi(mut a: u128, mut n: u128) -> i32 {
+    let mut t = 1;
+    a %= n;
+    while a != 0 {
+        let z = a.trailing_zeros();
+        a >>= z;
+        if z % 2 == 1 && (n % 8 == 3 || n % 8 == 5) {
+            t = -t;
+        }
+        if a % 4 == 3 && n % 4 == 3 {
+            t = -t;
+        }
+        let r = n % a;
+        n = a;
+        a = r;
+    }
+    if n == 1 {
+        t
+    } else {
+        0
+    }
+}
+
+/// Returns a nontrivial factor of the odd composite `n > u64::MAX`, like
+/// `pollard_brent`.
+fn pollard_brent_wide(n: u128) -> u128 {
+    const BATCH: u64 = 128;
+
+    let m = Montgomery::new(n);
+    let diff = |x: u128, y: u128| if x > y { x - y } else { y - x };
+
+    for c in 1.. {
+        let c = m.enter(c);
+        let step = |x: u128| m.add(m.mul(x, x), c);
+        let mut y = m.enter(2);
+        let mut x = y;
+        let mut ys = y;
+        let mut q = m.one;
+        let mut g = 1;
+        let mut r = 1;
+        while g == 1 {
+            x = y;
+            for _ in 0..r {
+                y = step(y);
+            }
+            let mut k = 0;
+            while k < r && g == 1 {
+                ys = y;
+                for _ in 0..cmp::min(BATCH, r - k) {
+                    y = step(y);
+                    q = m.mul(q, diff(x, y));
+                }
+                g = q.gcd(&n);
+                k += BATCH;
+            }
+            r *= 2;
+        }
+
+        if g == n {
+            loop {
+                ys = step(ys);
+                g = diff(x, ys).gcd(&n);
+                if g != 1 {
+                    break;
+                }
+            }
+        }
+        if g != n {
+            return g;
+        }
+    }
+    unreachable!()
+}
+
+/// Returns the number of primes less than or equal to `x` -- `π(x)`.
+///
+/// This uses the combinatorial sieve popularized by Lucy Hedgehog, which takes
+/// `O(x^¾)` time and `O(√x)` memory.
+///
+/// # Examples
+///
+/// ```
+/// use num_integer::prime_pi;
+///
+/// assert_eq!(prime_pi(0), 0);
+/// assert_eq!(prime_pi(2), 1);
+/// assert_eq!(prime_pi(100), 25);
+/// assert_eq!(prime_pi(1_000_000), 78_498);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn prime_pi(x: u64) -> u64 {
+    if x < 2 {
+        return 0;
+    }
+
+    // `small[v]` counts the survivors in `2..=v`, and `large[i]` the survivors
+    // in `2..=x/i`.  After sifting by every prime up to `√x`, only the primes
+    // are left.
+    let r = x.sqrt();
+    let mut small: Vec<u64> = (0..r + 1).map(|v| v.saturating_sub(1)).collect();
+    let mut large: Vec<u64> = (0..r + 1)
+        .map(|i| x.checked_div(i).map_or(0, |q| q - 1))
+        .collect();
+
+    for p in 2..r + 1 {
+        let sp = small[p as usize - 1];
+        if small[p as usize] == sp {
+            // `p` was sifted out, so it's composite.
+            continue;
+        }
+        let p2 = p * p;
+
+        let end = cmp::min(r, x / p2);
+        for i in 1..end + 1 {
+            let d = i * p;
+            let v = if d <= r {
+                large[d as usize]
+            } else {
+                small[(x / d) as usize]
+            };
+            large[i as usize] -= v - sp;
+        }
+
+        let mut v = r;
+        while v >= p2 {
+            small[v as usize] -= small[(v / p) as usize] - sp;
+            v -= 1;
+        }
+    }
+    large[1]
+}
+
+/// Returns the `n`th prime, counting from `nth_prime(1) == 2`.
+///
+/// The result is bracketed by the Rosser–Schoenfeld bounds
+/// `n (ln n + ln ln n - 1) < pₙ < n (ln n + ln ln n)`, evaluated in fixed-point
+/// arithmetic.  The primes up to the lower bound are counted with
+/// [`prime_pi`](fn.prime_pi.html), and the remaining gap is sieved.
+///
+/// # Panics
+///
+/// Panics if `n` is zero, or if the `n`th prime doesn't fit in a `u64`.
+///
+/// # Examples
+///
+/// ```
+/// use num_integer::nth_prime;
+///
+/// assert_eq!(nth_prime(1), 2);
+/// assert_eq!(nth_prime(10), 29);
+/// assert_eq!(nth_prime(1_000), 7_919);
+/// assert_eq!(nth_prime(1_000_000), 15_485_863);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn nth_prime(n: u64) -> u64 {
+    const SMALL: [u64; 5] = [2, 3, 5, 7, 11];
+
+    assert!(n > 0, "there is no zeroth prime");
+    if n <= SMALL.len() as u64 {
+        return SMALL[n as usize - 1];
+    }
+
+    let (lo, hi) = nth_prime_bounds(n);
+    let mut count = prime_pi(lo);
+    debug_assert!(count < n);
+
+    let mut nth = None;
+    for_each_prime(lo + 1, hi, |p| {
+        count += 1;
+        if count == n {
+            nth = Some(p);
+            false
+        } else {
+            true
+        }
+    });
+    nth.expect("the nth prime is out of range")
+}
+
+/// `ln 2` as a 0.64 fixed-point number.
+#[cfg(feature = "alloc")]
+const LN_2: u128 = 0xb172_17f7_d1cf_79ab;
+
+/// Returns `log₂(x)` as a truncated 32.32 fixed-point number.
+#[cfg(feature = "alloc")]
+fn log2_fixed(x: u64) -> u64 {
+    debug_assert!(x > 0);
+    let int = 63 - x.leading_zeros();
+
+    // Normalize the mantissa to [1, 2) with 63 fractional bits, then square it
+    // repeatedly to shift out one bit of the logarithm at a time.
+    let mut m = u128::from(x << (63 - int));
+    let mut frac = 0;
+    for i in (0..32).rev() {
+        m = (m * m) >> 63;
+        if m >> 64 != 0 {
+            m >>= 1;
+            frac |= 1 << i;
+        }
+    }
+    u64::from(int) << 32 | frac
+}
+
+/// Returns `ln(x)` as a truncated 32.32 fixed-point number.
+#[cfg(feature = "alloc")]
+fn ln_fixed(x: u64) -> u64 {
+    ((u128::from(log2_fixed(x)) * LN_2) >> 64) as u64
+}
+
+/// Returns bounds `(lo, hi)` such that `lo < pₙ ≤ hi`, for `n ≥ 6`.
+#[cfg(feature = "alloc")]
+fn nth_prime_bounds(n: u64) -> (u64, u64) {
+    debug_assert!(n >= 6);
+
+    // ln ln n, via the fixed-point ln n scaled by 2³²
+    let ln = ln_fixed(n);
+    let ln_ln = ln_fixed(ln) - ((32 * LN_2) >> 32) as u64;
+
+    // The fixed-point logarithms are only off in the last few bits, so a
+    // little slack on either side is plenty to keep the bounds valid.
+    let slack = (n >> 16) + 2;
+    let sum = u128::from(ln + ln_ln);
+    let lo = (u128::from(n) * (sum - (1 << 32))) >> 32;
+    let hi = (u128::from(n) * sum) >> 32;
+
+    let lo = (lo as u64).saturating_sub(slack);
+    let hi = if hi >> 64 == 0 {
+        (hi as u64).saturating_add(slack)
+    } else {
+        core::u64::MAX
+    };
+    (lo, hi)
+}
+
+/// Calls `f` with each prime in `lo..=hi` in increasing order, until it returns `false`.
+///
+/// This is a segmented sieve of Eratosthenes, so it only needs memory for the
+/// primes up to `√hi` and one fixed-size segment at a time.
+#[cfg(feature = "alloc")]
+pub(crate) fn for_each_prime<F>(lo: u64, hi: u64, mut f: F)
+where
+    F: FnMut(u64) -> bool,
+{
+    const SEGMENT: u64 = 1 << 16;
+
+    let lo = cmp::max(lo, 2);
+    if lo > hi {
+        return;
+    }
+
+    // The sieving primes are small enough to find with a plain sieve.
+    let root = hi.sqrt();
+    let base: Vec<u64> = {
+        let mut composite = Vec::new();
+        composite.resize(root as usize + 1, false);
+        let mut base = Vec::new();
+        for i in 2..root + 1 {
+            if !composite[i as usize] {
+                base.push(i);
+                let mut j = i * i;
+                while j <= root {
+                    composite[j as usize] = true;
+                    j += i;
+                }
+            }
+        }
+        base
+    };
+
+    let mut composite = Vec::new();
+    composite.resize(SEGMENT as usize, false);
+    let mut start = lo;
+    loop {
+        let end = cmp::min(hi, start.saturating_add(SEGMENT - 1));
+        let len = (end - start + 1) as usize;
+        for c in &mut composite[..len] {
+            *c = false;
+        }
+
+        for &p in &base {
+            let p2 = p * p;
+            if p2 > end {
+                break;
+            }
+            // The first multiple of `p` in the segment, skipping `p` itself.
+            let mut m = if p2 >= start {
+                p2
+            } else {
+                match start % p {
+                    0 => start,
+                    r => match start.checked_add(p - r) {
+                        Some(m) => m,
+                        None => continue,
+                    },
+                }
+            };
+            while m <= end {
+                composite[(m - start) as usize] = true;
+                m = match m.checked_add(p) {
+                    Some(m) => m,
+                    None => break,
+                };
+            }
+        }
+
+        for (i, &c) in composite[..len].iter().enumerate() {
+            if !c && !f(start + i as u64) {
+                return;
+            }
+        }
+
+        if end == hi {
+            return;
+        }
+        start = end + 1;
+    }
+}
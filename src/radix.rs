@@ -0,0 +1,224 @@
+use core::fmt::{self, Write};
+use core::str;
+use num_traits::PrimInt;
+
+/// The longest text a `RadixDisplay` can produce: a sign, then 128 binary
+/// digits with a separator between each pair.
+const MAX_LEN: usize = 1 + 128 + 127;
+
+/// Formats an integer in the given `radix` into the caller's buffer,
+/// returning the formatted text.
+///
+/// Digits past 9 are lowercase letters, and negative values get a leading
+/// `-`, as with `{:x}` but in any radix from 2 to 36.  This is the inverse of
+/// `Num::from_str_radix`.  The text is written at the end of `buf`, and 129
+/// bytes are always enough for a primitive integer.
+///
+/// For a sign on positive values, zero padding or digit grouping, see
+/// [`RadixDisplay`](struct.RadixDisplay.html).
+///
+/// # Panics
+///
+/// Panics if `radix` is not in the range `2..=36`, or if `buf` is too small.
+///
+/// # Examples
+///
+/// ```
+/// use num_integer::format_radix;
+///
+/// let mut buf = [0u8; 129];
+/// assert_eq!(format_radix(255u8, 16, &mut buf), "ff");
+/// assert_eq!(format_radix(-35i32, 36, &mut buf), "-z");
+/// assert_eq!(format_radix(std::i8::MIN, 2, &mut buf), "-10000000");
+/// ```
+pub fn format_radix<T: PrimInt>(value: T, radix: u32, buf: &mut [u8]) -> &str {
+    match RadixDisplay::new(value, radix).format_into(buf) {
+        Some(s) => s,
+        None => panic!("buffer too small to format the integer"),
+    }
+}
+
+/// An adapter to format an integer in any radix from 2 to 36, with
+/// [`Display`](https://doc.rust-lang.org/core/fmt/trait.Display.html) or
+/// into the caller's buffer.
+///
+/// The formatter's width, fill, alignment and the `+` and `0` flags work as
+/// they do for the primitive integers, so zero padding goes between the sign
+/// and the digits, and precision is ignored.
+///
+/// # Examples
+///
+/// ```
+/// use num_integer::RadixDisplay;
+///
+/// let x = RadixDisplay::new(-0xbeef_i32, 16);
+/// assert_eq!(format!("{}", x), "-beef");
+/// assert_eq!(format!("{:>8}", x.uppercase(true)), "   -BEEF");
+/// assert_eq!(format!("{:08}", x), "-000beef");
+///
+/// let x = RadixDisplay::new(5u8, 2).plus_sign(true).min_digits(8).group(4, '_');
+/// assert_eq!(format!("{}", x), "+0000_0101");
+///
+/// let mut buf = [0u8; 16];
+/// let x = RadixDisplay::new(1234567u32, 10).group(3, ',');
+/// assert_eq!(x.format_into(&mut buf), Some("1,234,567"));
+/// assert_eq!(x.format_into(&mut buf[..8]), None);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct RadixDisplay<T> {
+    value: T,
+    radix: u32,
+    uppercase: bool,
+    plus_sign: bool,
+    min_digits: usize,
+    group: Option<(usize, u8)>,
+}
+
+impl<T: PrimInt> RadixDisplay<T> {
+    /// Creates an adapter to format `value` in the given `radix`, with
+    /// lowercase letters, no sign unless it's negative, no padding and no
+    /// grouping.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in the range `2..=36`.
+    pub fn new(value: T, radix: u32) -> Self {
+        assert!(
+            2 <= radix && radix <= 36,
+            "radix must be in the range 2..=36"
+        );
+        RadixDisplay {
+            value,
+            radix,
+            uppercase: false,
+            plus_sign: false,
+            min_digits: 1,
+            group: None,
+        }
+    }
+
+    /// Sets whether digits past 9 are uppercase letters.
+    pub fn uppercase(mut self, uppercase: bool) -> Self {
+        self.uppercase = uppercase;
+        self
+    }
+
+    /// Sets whether non-negative values get a leading `+`.
+    pub fn plus_sign(mut self, plus_sign: bool) -> Self {
+        self.plus_sign = plus_sign;
+        self
+    }
+
+    /// Pads the value with leading zeros to at least `digits` digits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `digits` is more than 128.
+    pub fn min_digits(mut self, digits: usize) -> Self {
+        assert!(digits <= 128, "at most 128 digits of padding are supported");
+        self.min_digits = digits;
+        self
+    }
+
+    /// Separates the digits into groups of `size` with `separator`, counting
+    /// from the least significant digit.  Padding zeros are grouped too.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero or `separator` isn't ASCII.
+    pub fn group(mut self, size: usize, separator: char) -> Self {
+        assert!(size > 0, "digit groups can't be empty");
+        assert!(separator.is_ascii(), "the group separator must be ASCII");
+        self.group = Some((size, separator as u8));
+        self
+    }
+
+    /// Formats the value into the end of `buf`, returning the formatted text,
+    /// or `None` if it doesn't fit.
+    pub fn format_into<'a>(&self, buf: &'a mut [u8]) -> Option<&'a str> {
+        let (negative, mut i) = self.write_digits(buf)?;
+        if negative || self.plus_sign {
+            i = i.checked_sub(1)?;
+            buf[i] = if negative { b'-' } else { b'+' };
+        }
+        str::from_utf8(&buf[i..]).ok()
+    }
+
+    /// Writes the digits of the magnitude into the end of `buf`, returning
+    /// whether the value is negative and where the digits start, or `None` if
+    /// they don't fit.
+    fn write_digits(&self, buf: &mut [u8]) -> Option<(bool, usize)> {
+        let digits: &[u8; 36] = if self.uppercase {
+            b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ"
+        } else {
+            b"0123456789abcdefghijklmnopqrstuvwxyz"
+        };
+        let radix = u128::from(self.radix);
+        let (negative, mut m) = self.split();
+
+        let mut i = buf.len();
+        let mut count = 0;
+        loop {
+            if let Some((size, separator)) = self.group {
+                if count > 0 && count % size == 0 {
+                    i = i.checked_sub(1)?;
+                    buf[i] = separator;
+                }
+            }
+            i = i.checked_sub(1)?;
+            buf[i] = digits[(m % radix) as usize];
+            m /= radix;
+            count += 1;
+            if m == 0 && count >= self.min_digits {
+                break;
+            }
+        }
+        Some((negative, i))
+    }
+
+    /// Returns the sign and magnitude of the value.
+    fn split(&self) -> (bool, u128) {
+        if self.value < T::zero() {
+            let x = self
+                .value
+                .to_i128()
+                .expect("the value is a primitive integer");
+            (true, x.wrapping_abs() as u128)
+        } else {
+            let x = self
+                .value
+                .to_u128()
+                .expect("the value is a primitive integer");
+            (false, x)
+        }
+    }
+}
+
+impl<T: PrimInt> fmt::Display for RadixDisplay<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buf = [0u8; MAX_LEN];
+        let (negative, mut i) = match self.write_digits(&mut buf) {
+            Some(x) => x,
+            None => return Err(fmt::Error),
+        };
+
+        // `pad_integral` only signs non-negative values for the `+` flag, so
+        // sign them by hand otherwise.
+        if self.plus_sign && !negative && !f.sign_plus() {
+            let digits = str::from_utf8(&buf[i..]).map_err(|_| fmt::Error)?;
+            if f.sign_aware_zero_pad() {
+                f.write_char('+')?;
+                let len = digits.len() + 1;
+                for _ in len..f.width().unwrap_or(0) {
+                    f.write_char('0')?;
+                }
+                return f.write_str(digits);
+            }
+            // Without zero padding, the sign is just part of the padded text.
+            i -= 1;
+            buf[i] = b'+';
+        }
+        let digits = str::from_utf8(&buf[i..]).map_err(|_| fmt::Error)?;
+        f.pad_integral(!negative, "", digits)
+    }
+}
@@ -1,6 +1,6 @@
-use crate::Integer;
+use crate::{Integer, Logarithms};
 use core::mem;
-use num_traits::{checked_pow, PrimInt};
+use num_traits::checked_pow;
 
 /// Provides methods to compute an integer's square root, cube root,
 /// and arbitrary `n`th root.
@@ -106,6 +106,516 @@ pub trait Roots: Integer {
     fn cbrt(&self) -> Self {
         self.nth_root(3)
     }
+
+    /// Returns the truncated principal `n`th root of an integer, or `None` if
+    /// `n` is zero or if `n` is even and `self` is negative.
+    ///
+    /// This is the non-panicking version of [`nth_root`](#tymethod.nth_root).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_integer::Roots;
+    ///
+    /// assert_eq!(12345.checked_nth_root(4), Some(10));
+    /// assert_eq!((-12345).checked_nth_root(5), Some(-6));
+    /// assert_eq!((-12345).checked_nth_root(4), None);
+    /// assert_eq!(12345.checked_nth_root(0), None);
+    /// ```
+    #[inline]
+    fn checked_nth_root(&self, n: u32) -> Option<Self> {
+        if n == 0 || (n.is_even() && *self < Self::zero()) {
+            None
+        } else {
+            Some(self.nth_root(n))
+        }
+    }
+
+    /// Returns the truncated principal square root of an integer, or `None`
+    /// if `self` is negative.
+    ///
+    /// This is the non-panicking version of [`sqrt`](#method.sqrt).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_integer::Roots;
+    ///
+    /// assert_eq!(12345.checked_sqrt(), Some(111));
+    /// assert_eq!((-12345).checked_sqrt(), None);
+    /// ```
+    #[inline]
+    fn checked_sqrt(&self) -> Option<Self> {
+        if *self < Self::zero() {
+            None
+        } else {
+            Some(self.sqrt())
+        }
+    }
+
+    /// Returns the truncated principal cube root of an integer.
+    ///
+    /// Every integer has a real cube root, so this always returns `Some`, but
+    /// it's provided for symmetry with the other checked roots.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_integer::Roots;
+    ///
+    /// assert_eq!(12345.checked_cbrt(), Some(23));
+    /// assert_eq!((-12345).checked_cbrt(), Some(-23));
+    /// ```
+    #[inline]
+    fn checked_cbrt(&self) -> Option<Self> {
+        Some(self.cbrt())
+    }
+
+    /// Returns `true` if `self` is the square of an integer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_integer::Roots;
+    ///
+    /// assert!(0.is_perfect_square());
+    /// assert!(144.is_perfect_square());
+    /// assert!(!145.is_perfect_square());
+    /// assert!(!(-144).is_perfect_square());
+    /// ```
+    #[inline]
+    fn is_perfect_square(&self) -> bool
+    where
+        Self: Clone,
+    {
+        self.exact_sqrt().is_some()
+    }
+
+    /// Returns the square root of an integer if it's exact, or `None` if
+    /// `self` is not a perfect square.
+    ///
+    /// The implementations for primitive integers first check that `self` is
+    /// a quadratic residue modulo 64, 63, 65 and 11, which rejects more than
+    /// 99% of non-squares without computing a root at all.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_integer::Roots;
+    ///
+    /// assert_eq!(144.exact_sqrt(), Some(12));
+    /// assert_eq!(145.exact_sqrt(), None);
+    /// assert_eq!((-144).exact_sqrt(), None);
+    /// assert_eq!(std::u64::MAX.exact_sqrt(), None);
+    /// ```
+    #[inline]
+    fn exact_sqrt(&self) -> Option<Self>
+    where
+        Self: Clone,
+    {
+        let r = self.checked_sqrt()?;
+        if r.clone() * r.clone() == *self {
+            Some(r)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the principal `n`th root of an integer if it's exact, or
+    /// `None` if `self` is not an `n`th power.
+    ///
+    /// This also returns `None` if `n` is zero, or if `n` is even and `self`
+    /// is negative, since those roots don't exist.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_integer::Roots;
+    ///
+    /// assert_eq!(1024.exact_nth_root(5), Some(4));
+    /// assert_eq!(1025.exact_nth_root(5), None);
+    /// assert_eq!((-1024).exact_nth_root(5), Some(-4));
+    /// assert_eq!((-1024).exact_nth_root(10), None);
+    /// assert_eq!(std::i64::MIN.exact_nth_root(63), Some(-2));
+    /// ```
+    #[inline]
+    fn exact_nth_root(&self, n: u32) -> Option<Self>
+    where
+        Self: Clone,
+    {
+        let r = self.checked_nth_root(n)?;
+        if num_traits::pow(r.clone(), n as usize) == *self {
+            Some(r)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the cube root of an integer if it's exact, or `None` if
+    /// `self` is not a perfect cube.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_integer::Roots;
+    ///
+    /// assert_eq!(1728.exact_cbrt(), Some(12));
+    /// assert_eq!((-1728).exact_cbrt(), Some(-12));
+    /// assert_eq!(1729.exact_cbrt(), None);
+    /// ```
+    #[inline]
+    fn exact_cbrt(&self) -> Option<Self>
+    where
+        Self: Clone,
+    {
+        let r = self.cbrt();
+        if r.clone() * r.clone() * r.clone() == *self {
+            Some(r)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the truncated principal `n`th root of an integer, and the
+    /// remainder -- `(r, x - rⁿ)` where `r` is [`nth_root`](#tymethod.nth_root).
+    ///
+    /// The remainder has the same sign as `x`, and `rⁿ` never overflows
+    /// since its magnitude is no greater than `x`.
+    ///
+    /// # Panics
+    ///
+    /// Panics under the same conditions as [`nth_root`](#tymethod.nth_root).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_integer::Roots;
+    ///
+    /// assert_eq!(100.nth_root_rem(3), (4, 36));
+    /// assert_eq!((-100).nth_root_rem(3), (-4, -36));
+    /// assert_eq!(std::u64::MAX.nth_root_rem(5), (7131, 7_114_933_042_826_964));
+    /// ```
+    #[inline]
+    fn nth_root_rem(&self, n: u32) -> (Self, Self)
+    where
+        Self: Clone,
+    {
+        let r = self.nth_root(n);
+        let rem = self.clone() - num_traits::pow(r.clone(), n as usize);
+        (r, rem)
+    }
+
+    /// Returns the truncated principal square root of an integer, and the
+    /// remainder -- `(r, x - r²)` where `r` is [`sqrt`](#method.sqrt).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is less than zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_integer::Roots;
+    ///
+    /// assert_eq!(99.sqrt_rem(), (9, 18));
+    /// assert_eq!(100.sqrt_rem(), (10, 0));
+    /// assert_eq!(std::u32::MAX.sqrt_rem(), (65535, 131_070));
+    /// ```
+    #[inline]
+    fn sqrt_rem(&self) -> (Self, Self)
+    where
+        Self: Clone,
+    {
+        let r = self.sqrt();
+        let rem = self.clone() - r.clone() * r.clone();
+        (r, rem)
+    }
+
+    /// Returns the principal `n`th root of an integer, rounded as directed by
+    /// `rounding`.
+    ///
+    /// An inexact integer root can never lie exactly halfway between two
+    /// integers, so [`Rounding::Nearest`](enum.Rounding.html#variant.Nearest)
+    /// has no ties to break.
+    ///
+    /// The default implementation compares `2ⁿ·x` with `(2r+1)ⁿ` to round to
+    /// nearest, which may overflow a fixed-width type, but the implementations
+    /// for primitive integers are exact for every input.
+    ///
+    /// # Panics
+    ///
+    /// Panics under the same conditions as [`nth_root`](#tymethod.nth_root).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_integer::{Roots, Rounding};
+    ///
+    /// assert_eq!(30.nth_root_rounded(3, Rounding::Floor), 3);
+    /// assert_eq!(30.nth_root_rounded(3, Rounding::Ceiling), 4);
+    /// assert_eq!(30.nth_root_rounded(3, Rounding::Nearest), 3); // ∛30 ≈ 3.107
+    /// assert_eq!(50.nth_root_rounded(3, Rounding::Nearest), 4); // ∛50 ≈ 3.684
+    ///
+    /// assert_eq!((-30).nth_root_rounded(3, Rounding::Floor), -4);
+    /// assert_eq!((-30).nth_root_rounded(3, Rounding::Ceiling), -3);
+    /// assert_eq!((-30).nth_root_rounded(3, Rounding::TowardZero), -3);
+    /// assert_eq!((-30).nth_root_rounded(3, Rounding::AwayFromZero), -4);
+    ///
+    /// assert_eq!((1u64 << 63).nth_root_rounded(100, Rounding::Nearest), 2);
+    /// ```
+    fn nth_root_rounded(&self, n: u32, rounding: Rounding) -> Self
+    where
+        Self: Clone,
+    {
+        let (r, rem) = self.nth_root_rem(n);
+        if rem.is_zero() {
+            return r;
+        }
+        let negative = rem < Self::zero();
+        let away = match rounding {
+            Rounding::TowardZero => false,
+            Rounding::AwayFromZero => true,
+            Rounding::Floor => negative,
+            Rounding::Ceiling => !negative,
+            Rounding::Nearest => {
+                // |x| ≥ (|r| + ½)ⁿ  ⇔  2ⁿ·|x| ≥ (2|r| + 1)ⁿ, which is never equal.
+                let one = Self::one();
+                let two = one.clone() + one.clone();
+                let (x, r) = if negative {
+                    (Self::zero() - self.clone(), Self::zero() - r.clone())
+                } else {
+                    (self.clone(), r.clone())
+                };
+                let n = n as usize;
+                num_traits::pow(two.clone(), n) * x > num_traits::pow(two * r + one, n)
+            }
+        };
+        if !away {
+            r
+        } else if negative {
+            r - Self::one()
+        } else {
+            r + Self::one()
+        }
+    }
+
+    /// Returns the ceiling of the principal `n`th root of an integer --
+    /// `⌈ⁿ√x⌉`
+    ///
+    /// For negative `x` and odd `n`, this is the same as the truncated
+    /// [`nth_root`](#tymethod.nth_root), since that rounds toward zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics under the same conditions as [`nth_root`](#tymethod.nth_root).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_integer::Roots;
+    ///
+    /// assert_eq!(1000.nth_root_ceil(3), 10);
+    /// assert_eq!(1001.nth_root_ceil(3), 11);
+    /// assert_eq!(12345.nth_root_ceil(4), 11);
+    /// assert_eq!((-12345).nth_root_ceil(5), -6);
+    /// ```
+    #[inline]
+    fn nth_root_ceil(&self, n: u32) -> Self
+    where
+        Self: Clone,
+    {
+        self.nth_root_rounded(n, Rounding::Ceiling)
+    }
+
+    /// Returns the ceiling of the principal square root of an integer -- `⌈√x⌉`
+    ///
+    /// This is the smallest `r` with `x ≤ r²`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is less than zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_integer::Roots;
+    ///
+    /// assert_eq!(0.sqrt_ceil(), 0);
+    /// assert_eq!(100.sqrt_ceil(), 10);
+    /// assert_eq!(101.sqrt_ceil(), 11);
+    /// assert_eq!(std::u64::MAX.sqrt_ceil(), 1 << 32);
+    /// ```
+    #[inline]
+    fn sqrt_ceil(&self) -> Self
+    where
+        Self: Clone,
+    {
+        self.nth_root_rounded(2, Rounding::Ceiling)
+    }
+
+    /// Returns the ceiling of the principal cube root of an integer -- `⌈∛x⌉`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_integer::Roots;
+    ///
+    /// assert_eq!(1728.cbrt_ceil(), 12);
+    /// assert_eq!(1729.cbrt_ceil(), 13);
+    /// assert_eq!((-1729).cbrt_ceil(), -12);
+    /// ```
+    #[inline]
+    fn cbrt_ceil(&self) -> Self
+    where
+        Self: Clone,
+    {
+        self.nth_root_rounded(3, Rounding::Ceiling)
+    }
+
+    /// Returns the length of the hypotenuse of a right triangle with legs
+    /// `self` and `other` -- `⌊√(a² + b²)⌋`
+    ///
+    /// The implementations for primitive integers are exact even when the
+    /// squares overflow, widening internally to as much as 256 bits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result doesn't fit in `Self`:
+    ///
+    /// ```should_panic
+    /// # use num_integer::Roots;
+    /// println!("too long... {}", std::u32::MAX.hypot(&std::u32::MAX));
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_integer::Roots;
+    ///
+    /// assert_eq!(3.hypot(&4), 5);
+    /// assert_eq!((-5).hypot(&12), 13);
+    /// assert_eq!(std::i32::MAX.hypot(&1000), std::i32::MAX);
+    /// assert_eq!((1u128 << 126).hypot(&(1 << 126)), 0x5a82_7999_fcef_3242_2cbe_c4d9_baa5_5f4f);
+    /// ```
+    #[inline]
+    fn hypot(&self, other: &Self) -> Self
+    where
+        Self: Clone,
+    {
+        Self::norm(&[self.clone(), other.clone()])
+    }
+
+    /// Returns the Euclidean length of a vector of integers -- `⌊√(Σ xᵢ²)⌋`
+    ///
+    /// The implementations for primitive integers are exact even when the
+    /// squares or their sum overflow, widening internally to as much as 256 bits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result doesn't fit in `Self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_integer::Roots;
+    ///
+    /// assert_eq!(i32::norm(&[]), 0);
+    /// assert_eq!(i32::norm(&[2, -3, 6]), 7);
+    /// assert_eq!(u8::norm(&[100, 100, 100]), 173);
+    /// ```
+    fn norm(v: &[Self]) -> Self
+    where
+        Self: Clone,
+    {
+        let sum = v
+            .iter()
+            .fold(Self::zero(), |sum, x| sum + x.clone() * x.clone());
+        sum.sqrt()
+    }
+
+    /// Returns the `n`th root of an integer in fixed point with `frac_bits`
+    /// fractional bits -- `⌊ⁿ√x · 2^frac_bits⌋`, or `None` if that overflows.
+    ///
+    /// This is the truncated root of `x · 2^(n·frac_bits)`, so a negative `x`
+    /// rounds toward zero just like [`nth_root`](#tymethod.nth_root).
+    ///
+    /// The implementations for primitive integers are exact, widening
+    /// internally to as much as 8192 bits, and without `std` they never use
+    /// floating point.  They only return `None` if the root doesn't fit in
+    /// `Self`, or if the scaled `x · 2^(n·frac_bits)` exceeds those 8192 bits.
+    /// The default implementation assumes the scaling can't overflow, as for big
+    /// integers.
+    ///
+    /// # Panics
+    ///
+    /// Panics under the same conditions as [`nth_root`](#tymethod.nth_root).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_integer::Roots;
+    ///
+    /// // ∛2 ≈ 1.259921 in Q16.16
+    /// assert_eq!(2u32.nth_root_scaled(3, 16), Some(82_570));
+    /// assert_eq!((-2i32).nth_root_scaled(3, 16), Some(-82_570));
+    /// assert_eq!(2u32.nth_root_scaled(3, 32), None);
+    /// assert_eq!(2u32.nth_root_scaled(100, 3), Some(8));
+    /// assert_eq!(2u128.nth_root_scaled(5, 52), Some(5_173_277_483_525_748));
+    /// assert_eq!(std::u128::MAX.nth_root_scaled(4, 31), Some(std::u64::MAX as u128 >> 1));
+    /// ```
+    #[inline]
+    fn nth_root_scaled(&self, n: u32, frac_bits: u32) -> Option<Self>
+    where
+        Self: Clone,
+    {
+        let two = Self::one() + Self::one();
+        let scale = num_traits::pow(two, n as usize * frac_bits as usize);
+        Some((self.clone() * scale).nth_root(n))
+    }
+
+    /// Returns the square root of an integer in fixed point with `frac_bits`
+    /// fractional bits -- `⌊√x · 2^frac_bits⌋`, or `None` if that overflows.
+    ///
+    /// See [`nth_root_scaled`](#method.nth_root_scaled) for details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is less than zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_integer::Roots;
+    ///
+    /// // √2 ≈ 1.41421356 in Q16.16 and Q2.62
+    /// assert_eq!(2u32.sqrt_scaled(16), Some(92_681));
+    /// assert_eq!(2u64.sqrt_scaled(62), Some(6_521_908_912_666_391_106));
+    /// assert_eq!(2u64.sqrt_scaled(64), None);
+    /// assert_eq!(std::u128::MAX.sqrt_scaled(64), Some(std::u128::MAX));
+    /// ```
+    #[inline]
+    fn sqrt_scaled(&self, frac_bits: u32) -> Option<Self>
+    where
+        Self: Clone,
+    {
+        self.nth_root_scaled(2, frac_bits)
+    }
+}
+
+/// The direction in which to round an inexact root, for
+/// [Roots::nth_root_rounded](trait.Roots.html#method.nth_root_rounded).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Rounding {
+    /// Round toward negative infinity -- `⌊ⁿ√x⌋`
+    Floor,
+    /// Round toward positive infinity -- `⌈ⁿ√x⌉`
+    Ceiling,
+    /// Round toward zero, like [Roots::nth_root](trait.Roots.html#tymethod.nth_root).
+    TowardZero,
+    /// Round away from zero.
+    AwayFromZero,
+    /// Round to the nearest integer.
+    Nearest,
 }
 
 /// Returns the truncated principal square root of an integer --
@@ -129,23 +639,350 @@ pub fn nth_root<T: Roots>(x: T, n: u32) -> T {
     x.nth_root(n)
 }
 
+/// Returns the truncated principal square root of an integer, or `None` if it's negative --
+/// see [Roots::checked_sqrt](trait.Roots.html#method.checked_sqrt).
+#[inline]
+pub fn checked_sqrt<T: Roots>(x: T) -> Option<T> {
+    x.checked_sqrt()
+}
+
+/// Returns the truncated principal cube root of an integer --
+/// see [Roots::checked_cbrt](trait.Roots.html#method.checked_cbrt).
+#[inline]
+pub fn checked_cbrt<T: Roots>(x: T) -> Option<T> {
+    x.checked_cbrt()
+}
+
+/// Returns the truncated principal `n`th root of an integer, or `None` if it's imaginary
+/// or `n` is zero -- see [Roots::checked_nth_root](trait.Roots.html#method.checked_nth_root).
+#[inline]
+pub fn checked_nth_root<T: Roots>(x: T, n: u32) -> Option<T> {
+    x.checked_nth_root(n)
+}
+
+/// Returns the truncated principal square root of an integer and the remainder --
+/// see [Roots::sqrt_rem](trait.Roots.html#method.sqrt_rem).
+#[inline]
+pub fn sqrt_rem<T: Roots + Clone>(x: T) -> (T, T) {
+    x.sqrt_rem()
+}
+
+/// Returns the truncated principal `n`th root of an integer and the remainder --
+/// see [Roots::nth_root_rem](trait.Roots.html#method.nth_root_rem).
+#[inline]
+pub fn nth_root_rem<T: Roots + Clone>(x: T, n: u32) -> (T, T) {
+    x.nth_root_rem(n)
+}
+
+/// Returns the square root of an integer in fixed point, `⌊√x · 2^frac_bits⌋` --
+/// see [Roots::sqrt_scaled](trait.Roots.html#method.sqrt_scaled).
+#[inline]
+pub fn sqrt_scaled<T: Roots + Clone>(x: T, frac_bits: u32) -> Option<T> {
+    x.sqrt_scaled(frac_bits)
+}
+
+/// Returns the `n`th root of an integer in fixed point, `⌊ⁿ√x · 2^frac_bits⌋` --
+/// see [Roots::nth_root_scaled](trait.Roots.html#method.nth_root_scaled).
+#[inline]
+pub fn nth_root_scaled<T: Roots + Clone>(x: T, n: u32, frac_bits: u32) -> Option<T> {
+    x.nth_root_scaled(n, frac_bits)
+}
+
+/// Returns the length of the hypotenuse of a right triangle, `⌊√(a² + b²)⌋` --
+/// see [Roots::hypot](trait.Roots.html#method.hypot).
+#[inline]
+pub fn hypot<T: Roots + Clone>(a: T, b: T) -> T {
+    a.hypot(&b)
+}
+
+/// Returns the Euclidean length of a vector of integers, `⌊√(Σ xᵢ²)⌋` --
+/// see [Roots::norm](trait.Roots.html#method.norm).
+#[inline]
+pub fn norm<T: Roots + Clone>(v: &[T]) -> T {
+    T::norm(v)
+}
+
+/// Returns the base and maximal exponent `(b, k)` such that `bᵏ = x` and
+/// `k ≥ 2`, or `None` if `x` is not a perfect power.
+///
+/// A negative `x` can only be an odd power of a negative base.  The values
+/// `0`, `1` and `-1` are powers of themselves with every exponent, so there
+/// is no maximal exponent and they return `None`.
+///
+/// # Examples
+///
+/// ```
+/// use num_integer::is_perfect_power;
+///
+/// assert_eq!(is_perfect_power(64), Some((2, 6)));
+/// assert_eq!(is_perfect_power(-64), Some((-4, 3)));
+/// assert_eq!(is_perfect_power(36), Some((6, 2)));
+/// assert_eq!(is_perfect_power(-36), None);
+/// assert_eq!(is_perfect_power(72), None);
+/// assert_eq!(is_perfect_power(std::i8::MIN), Some((-2, 7)));
+/// ```
+pub fn is_perfect_power<T: Roots + Clone>(x: T) -> Option<(T, u32)> {
+    let negative = x < T::zero();
+    if x.is_zero() || x.is_one() || (negative && (x.clone() + T::one()).is_zero()) {
+        return None;
+    }
+
+    // Pull out each prime exponent in turn.  Once the root of some degree is
+    // ±1, the base is too small for any higher degree as well.
+    let mut base = x;
+    let mut exp = 1;
+    let mut p = 2;
+    loop {
+        if !(negative && p == 2) {
+            loop {
+                let r = base.nth_root(p);
+                if r.is_one() || (negative && (r.clone() + T::one()).is_zero()) {
+                    return if exp > 1 { Some((base, exp)) } else { None };
+                }
+                if num_traits::pow(r.clone(), p as usize) != base {
+                    break;
+                }
+                base = r;
+                exp *= p;
+            }
+        }
+        p = next_prime(p);
+    }
+}
+
+/// Returns the smallest prime greater than `p`.
+fn next_prime(mut p: u32) -> u32 {
+    loop {
+        p += 1;
+        if (2..p).take_while(|d| d * d <= p).all(|d| p % d != 0) {
+            return p;
+        }
+    }
+}
+
+/// Returns the truncated principal `n`th root of any integer type, by
+/// Newton's method -- see [Roots::nth_root](trait.Roots.html#tymethod.nth_root).
+///
+/// This only needs `Integer + Clone`, so it can be used to implement `Roots`
+/// for big or custom integer types.  The primitive implementations of `Roots`
+/// are faster and should be preferred for those types.
+///
+/// The iteration starts from a power of two just above the root, and computes
+/// powers up to `(2·ⁿ√x)ⁿ⁻¹`.  That can't overflow an arbitrary-precision type,
+/// but a fixed-width type needs that much headroom, as well as room for `-x`
+/// if `x` is negative.
+///
+/// # Panics
+///
+/// Panics if `n` is zero, or if `n` is even and `x` is negative.
+///
+/// # Examples
+///
+/// ```
+/// use num_integer::{generic_nth_root, Integer, Roots};
+///
+/// # #[derive(Clone, Debug, PartialEq, PartialOrd)]
+/// # struct Big(i128);
+/// # impl num_traits::Zero for Big {
+/// #     fn zero() -> Self { Big(0) }
+/// #     fn is_zero(&self) -> bool { self.0 == 0 }
+/// # }
+/// # impl num_traits::One for Big {
+/// #     fn one() -> Self { Big(1) }
+/// # }
+/// # macro_rules! op {
+/// #     ($($Op:ident $op:ident),*) => {$(
+/// #         impl std::ops::$Op for Big {
+/// #             type Output = Big;
+/// #             fn $op(self, other: Big) -> Big { Big((self.0).$op(other.0)) }
+/// #         }
+/// #     )*};
+/// # }
+/// # op!(Add add, Sub sub, Mul mul, Div div, Rem rem);
+/// # impl num_traits::Num for Big {
+/// #     type FromStrRadixErr = <i128 as num_traits::Num>::FromStrRadixErr;
+/// #     fn from_str_radix(s: &str, r: u32) -> Result<Self, Self::FromStrRadixErr> {
+/// #         i128::from_str_radix(s, r).map(Big)
+/// #     }
+/// # }
+/// # impl Eq for Big {}
+/// # impl Ord for Big {
+/// #     fn cmp(&self, other: &Big) -> std::cmp::Ordering { self.0.cmp(&other.0) }
+/// # }
+/// # impl Integer for Big {
+/// #     fn div_floor(&self, other: &Big) -> Big { Big(self.0.div_floor(&other.0)) }
+/// #     fn mod_floor(&self, other: &Big) -> Big { Big(self.0.mod_floor(&other.0)) }
+/// #     fn gcd(&self, other: &Big) -> Big { Big(self.0.gcd(&other.0)) }
+/// #     fn lcm(&self, other: &Big) -> Big { Big(self.0.lcm(&other.0)) }
+/// #     fn is_multiple_of(&self, other: &Big) -> bool { self.0.is_multiple_of(&other.0) }
+/// #     fn is_even(&self) -> bool { self.0.is_even() }
+/// #     fn is_odd(&self) -> bool { self.0.is_odd() }
+/// #     fn div_rem(&self, other: &Big) -> (Big, Big) {
+/// #         let (q, r) = self.0.div_rem(&other.0);
+/// #         (Big(q), Big(r))
+/// #     }
+/// # }
+/// // struct Big(...) implements `Integer`, but has no `Roots` of its own.
+/// impl Roots for Big {
+///     fn nth_root(&self, n: u32) -> Self {
+///         generic_nth_root(self.clone(), n)
+///     }
+/// }
+///
+/// assert_eq!(Big(12345).nth_root(4), Big(10));
+/// assert_eq!(Big(-12345).cbrt(), Big(-23));
+/// assert_eq!(Big(1 << 100).sqrt(), Big(1 << 50));
+/// ```
+pub fn generic_nth_root<T: Integer + Clone>(x: T, n: u32) -> T {
+    if n == 0 {
+        panic!("can't find a root of degree 0!");
+    }
+    if x < T::zero() {
+        if n.is_even() {
+            panic!("even roots of a negative are imaginary");
+        }
+        if n == 1 {
+            return x;
+        }
+        // `-x` may overflow, so take the root of `-(x + 1)` instead, and then
+        // see if one more is still within `x`, which `x / sⁿ⁻¹ ≤ -s` tells.
+        let r = generic_root(T::zero() - (x.clone() + T::one()), n);
+        let s = r.clone() + T::one();
+        let mut q = x;
+        for _ in 1..n {
+            if q.is_zero() {
+                break;
+            }
+            q = q / s.clone();
+        }
+        if q <= T::zero() - s.clone() {
+            T::zero() - s
+        } else {
+            T::zero() - r
+        }
+    } else {
+        generic_root(x, n)
+    }
+}
+
+/// Returns the truncated principal square root of any integer type --
+/// see [generic_nth_root](fn.generic_nth_root.html).
+///
+/// # Panics
+///
+/// Panics if `x` is negative.
+#[inline]
+pub fn generic_sqrt<T: Integer + Clone>(x: T) -> T {
+    if x < T::zero() {
+        panic!("the square root of a negative is imaginary");
+    }
+    generic_root(x, 2)
+}
+
+/// Returns the truncated principal cube root of any integer type --
+/// see [generic_nth_root](fn.generic_nth_root.html).
+#[inline]
+pub fn generic_cbrt<T: Integer + Clone>(x: T) -> T {
+    generic_nth_root(x, 3)
+}
+
+/// Returns `⌊ⁿ√a⌋` for `a ≥ 0` and `n > 0`.
+fn generic_root<T: Integer + Clone>(a: T, n: u32) -> T {
+    if n == 1 || a.is_zero() {
+        return a;
+    }
+
+    // Find the bit length of `a`, without ever doubling past it.
+    let two = T::one() + T::one();
+    let half = a.clone() / two.clone();
+    let mut p = T::one();
+    let mut bits = 1;
+    while p <= half {
+        p = p * two.clone();
+        bits += 1;
+    }
+
+    // The root of values less than 2ⁿ can only be 1.
+    if bits <= n {
+        return T::one();
+    }
+
+    // ⁿ√a < 2^(bits/n), so start from above and let Newton's method descend.
+    let mut guess = T::one();
+    for _ in 0..(bits + n - 1) / n {
+        guess = guess * two.clone();
+    }
+
+    // `n < bits` here, so building `n - 1` by doubling can't overflow `T`.
+    let e = n - 1;
+    let n1 = (0..32).rev().fold(T::zero(), |acc, i| {
+        let acc = acc.clone() + acc;
+        if (n - 1) >> i & 1 != 0 {
+            acc + T::one()
+        } else {
+            acc
+        }
+    });
+    let n = n1.clone() + T::one();
+
+    // https://en.wikipedia.org/wiki/Nth_root_algorithm
+    let next = |x: &T| {
+        // `⌊a / xⁿ⁻¹⌋` by repeated division, as `xⁿ⁻¹` itself may overflow.
+        let mut y = a.clone();
+        for _ in 0..e {
+            if y.is_zero() {
+                break;
+            }
+            y = y / x.clone();
+        }
+        (y + x.clone() * n1.clone()) / n.clone()
+    };
+    fixpoint(guess, next)
+}
+
 macro_rules! signed_roots {
     ($T:ty, $U:ty) => {
         impl Roots for $T {
             #[inline]
             fn nth_root(&self, n: u32) -> Self {
+                match self.checked_nth_root(n) {
+                    Some(r) => r,
+                    None if n == 0 => panic!("can't find a root of degree 0!"),
+                    None => panic!("even roots of a negative are imaginary"),
+                }
+            }
+
+            #[inline]
+            fn checked_nth_root(&self, n: u32) -> Option<Self> {
                 if *self >= 0 {
-                    (*self as $U).nth_root(n) as Self
+                    (*self as $U).checked_nth_root(n).map(|r| r as Self)
+                } else if n.is_odd() {
+                    Some(((self.wrapping_neg() as $U).nth_root(n) as Self).wrapping_neg())
                 } else {
-                    assert!(n.is_odd(), "even roots of a negative are imaginary");
-                    -((self.wrapping_neg() as $U).nth_root(n) as Self)
+                    None
                 }
             }
 
             #[inline]
             fn sqrt(&self) -> Self {
-                assert!(*self >= 0, "the square root of a negative is imaginary");
-                (*self as $U).sqrt() as Self
+                match self.checked_sqrt() {
+                    Some(r) => r,
+                    None => panic!("the square root of a negative is imaginary"),
+                }
+            }
+
+            #[inline]
+            fn checked_sqrt(&self) -> Option<Self> {
+                if *self >= 0 {
+                    Some((*self as $U).sqrt() as Self)
+                } else {
+                    None
+                }
+            }
+
+            #[inline]
+            fn checked_cbrt(&self) -> Option<Self> {
+                Some(self.cbrt())
             }
 
             #[inline]
@@ -153,7 +990,105 @@ macro_rules! signed_roots {
                 if *self >= 0 {
                     (*self as $U).cbrt() as Self
                 } else {
-                    -((self.wrapping_neg() as $U).cbrt() as Self)
+                    ((self.wrapping_neg() as $U).cbrt() as Self).wrapping_neg()
+                }
+            }
+
+            #[inline]
+            fn is_perfect_square(&self) -> bool {
+                self.exact_sqrt().is_some()
+            }
+
+            #[inline]
+            fn exact_sqrt(&self) -> Option<Self> {
+                if *self >= 0 {
+                    (*self as $U).exact_sqrt().map(|r| r as Self)
+                } else {
+                    None
+                }
+            }
+
+            #[inline]
+            fn exact_nth_root(&self, n: u32) -> Option<Self> {
+                if *self >= 0 {
+                    (*self as $U).exact_nth_root(n).map(|r| r as Self)
+                } else if n.is_odd() {
+                    let r = (self.wrapping_neg() as $U).exact_nth_root(n)?;
+                    Some((r as Self).wrapping_neg())
+                } else {
+                    None
+                }
+            }
+
+            #[inline]
+            fn exact_cbrt(&self) -> Option<Self> {
+                self.exact_nth_root(3)
+            }
+
+            #[inline]
+            fn nth_root_rem(&self, n: u32) -> (Self, Self) {
+                let r = self.nth_root(n);
+                (r, *self - r.pow(n))
+            }
+
+            #[inline]
+            fn sqrt_rem(&self) -> (Self, Self) {
+                let r = self.sqrt();
+                (r, *self - r * r)
+            }
+
+            #[inline]
+            fn hypot(&self, other: &Self) -> Self {
+                Self::norm(&[*self, *other])
+            }
+
+            #[inline]
+            fn norm(v: &[Self]) -> Self {
+                let xs = v.iter().map(|&x| x.wrapping_abs() as $U as u128);
+                match norm_u128(xs, Self::max_value() as u128) {
+                    Some(r) => r as Self,
+                    None => panic!("the norm overflows the integer type"),
+                }
+            }
+
+            #[inline]
+            fn nth_root_scaled(&self, n: u32, frac_bits: u32) -> Option<Self> {
+                if *self >= 0 {
+                    let r = (*self as $U).nth_root_scaled(n, frac_bits)?;
+                    if r <= Self::max_value() as $U {
+                        Some(r as Self)
+                    } else {
+                        None
+                    }
+                } else if n.is_odd() {
+                    let r = (self.wrapping_neg() as $U).nth_root_scaled(n, frac_bits)?;
+                    if r <= Self::min_value() as $U {
+                        Some((r as Self).wrapping_neg())
+                    } else {
+                        None
+                    }
+                } else {
+                    // panics for `n == 0` and imaginary roots alike
+                    Some(self.nth_root(n))
+                }
+            }
+
+            #[inline]
+            fn nth_root_rounded(&self, n: u32, rounding: Rounding) -> Self {
+                if *self >= 0 {
+                    (*self as $U).nth_root_rounded(n, rounding) as Self
+                } else if n.is_odd() {
+                    // Round the magnitude instead, where flooring is away from zero.
+                    let rounding = match rounding {
+                        Rounding::Floor => Rounding::Ceiling,
+                        Rounding::Ceiling => Rounding::Floor,
+                        r => r,
+                    };
+                    let r = (self.wrapping_neg() as $U).nth_root_rounded(n, rounding);
+                    (r as Self).wrapping_neg()
+                } else {
+                    // panics for `n == 0` and imaginary roots alike
+                    self.nth_root(n)
                 }
             }
         }
@@ -170,30 +1105,329 @@ signed_roots!(isize, usize);
 #[inline]
 fn fixpoint<T, F>(mut x: T, f: F) -> T
 where
-    T: Integer + Copy,
-    F: Fn(T) -> T,
+    T: Integer,
+    F: Fn(&T) -> T,
 {
-    let mut xn = f(x);
+    let mut xn = f(&x);
     while x < xn {
         x = xn;
-        xn = f(x);
+        xn = f(&x);
     }
     while x > xn {
         x = xn;
-        xn = f(x);
+        xn = f(&x);
     }
     x
 }
 
+/// Returns `false` if `x` is definitely not a square, given `x mod 64` and
+/// `x mod 45045`, where `45045 = 63·65·11`.
+#[inline]
+fn is_square_residue(x_mod_64: u32, x_mod_45045: u32) -> bool {
+    // Each bit `i` of these masks is set if `i` is a square modulo the mask's length.
+    const SQUARES_MOD_64: u64 = 0x0202_0212_0203_0213;
+    const SQUARES_MOD_63: u64 = 0x0402_4830_1245_0293;
+    const SQUARES_MOD_65: u128 = 0x1_218a_0198_6601_4613;
+    const SQUARES_MOD_11: u16 = 0x023b;
+
+    SQUARES_MOD_64 >> x_mod_64 & 1 != 0
+        && SQUARES_MOD_63 >> (x_mod_45045 % 63) & 1 != 0
+        && SQUARES_MOD_65 >> (x_mod_45045 % 65) & 1 != 0
+        && SQUARES_MOD_11 >> (x_mod_45045 % 11) & 1 != 0
+}
+
+/// Returns `true` if `x ≥ (r + ½)ⁿ`, where `r` is the truncated `n`th root of `x`,
+/// meaning that `r + 1` is the nearest root.
+fn rounds_up(x: u128, r: u128, n: u32) -> bool {
+    match n {
+        1 => false,
+        2 => x - r * r > r,
+        // (r + ½)ⁿ ≥ 1.5²⁵⁶ > x, unless both `x` and `r` are zero.
+        _ if n >= 256 => false,
+        _ => {
+            // Compare 2ⁿ·x with (2r + 1)ⁿ in 384-bit little-endian limbs,
+            // which always holds the former for `n < 256`.  For `n ≥ 3`,
+            // `r < 2⁴³` so `2r + 1` fits in a single limb.
+            let mut lhs = [0u64; 6];
+            let (shift, bits) = ((n / 64) as usize, n % 64);
+            for (i, &limb) in [x as u64, (x >> 64) as u64].iter().enumerate() {
+                lhs[i + shift] |= limb << bits;
+                if bits > 0 {
+                    lhs[i + shift + 1] |= limb >> (64 - bits);
+                }
+            }
+
+            let m = u128::from(2 * r as u64 + 1);
+            let mut rhs = [0u64; 6];
+            rhs[0] = 1;
+            for _ in 0..n {
+                let mut carry = 0;
+                for limb in rhs.iter_mut() {
+                    let t = u128::from(*limb) * m + carry;
+                    *limb = t as u64;
+                    carry = t >> 64;
+                }
+                if carry != 0 {
+                    // (2r + 1)ⁿ ≥ 2³⁸⁴ > 2ⁿ·x
+                    return false;
+                }
+            }
+            lhs.iter().rev().gt(rhs.iter().rev())
+        }
+    }
+}
+
+/// The capacity of the scaled radicands in `scaled_root`, in 64-bit limbs.
+const SCALED_LIMBS: usize = 128;
+
+/// Returns `⌊ⁿ√(x · 2ⁿᶠ)⌋ = ⌊ⁿ√x · 2ᶠ⌋` for `n > 0`, or `None` if that
+/// doesn't fit in a `u128` or `x · 2ⁿᶠ` needs more than `64 · SCALED_LIMBS`
+/// bits.
+fn scaled_root(x: u128, n: u32, f: u32) -> Option<u128> {
+    if x == 0 {
+        return Some(0);
+    }
+    // ⁿ√x ≥ 1, so the root is at least 2ᶠ.
+    if f >= 128 {
+        return None;
+    }
+    let len = u64::from(128 - x.leading_zeros()) + u64::from(n) * u64::from(f);
+    if len <= 128 {
+        return Some((x << (n * f)).nth_root(n));
+    }
+
+    // The root is in `[k·2ᶠ, (k+1)·2ᶠ)` for the integer root `k`, so only its
+    // `f` fractional bits are left to find, a bit at a time from the top.
+    let k = x.nth_root(n);
+    if k.leading_zeros() < f || len > 64 * SCALED_LIMBS as u64 {
+        return None;
+    }
+    let mut r = k << f;
+    for bit in (0..f).rev() {
+        let c = r | 1 << bit;
+        if scaled_pow_le(c, n, x, f) {
+            r = c;
+        }
+    }
+    Some(r)
+}
+
+/// Returns whether `cⁿ ≤ x · 2ⁿᶠ`, for a `c` that isn't a multiple of `2ᶠ`
+/// and an `x · 2ⁿᶠ` that fits in `SCALED_LIMBS` limbs.
+fn scaled_pow_le(c: u128, n: u32, x: u128, f: u32) -> bool {
+    // With `c = m·2ᵗ` for an odd `m`, compare `mⁿ` with `x · 2ⁿ⁽ᶠ⁻ᵗ⁾`.
+    let t = c.trailing_zeros();
+    let m = c >> t;
+    let shift = n as usize * (f - t) as usize;
+    let len = 128 - x.leading_zeros() as usize + shift;
+
+    let mut p = [0u64; SCALED_LIMBS + 2];
+    p[0] = 1;
+    let mut used = 1;
+    let (m0, m1) = (u128::from(m as u64), u128::from((m >> 64) as u64));
+    for _ in 0..n {
+        let mut carry = 0u128;
+        let mut prev = 0u64;
+        for limb in p[..used + 2].iter_mut() {
+            let (sum, o1) = (u128::from(*limb) * m0).overflowing_add(u128::from(prev) * m1);
+            let (sum, o2) = sum.overflowing_add(carry);
+            prev = *limb;
+            *limb = sum as u64;
+            carry = (sum >> 64) + (u128::from(o1 as u8 + o2 as u8) << 64);
+        }
+        used += 2;
+        while p[used - 1] == 0 {
+            used -= 1;
+        }
+        if 64 * used - p[used - 1].leading_zeros() as usize > len {
+            return false;
+        }
+    }
+
+    // The limbs of `x · 2ˢʰⁱᶠᵗ`, which are zero outside `xs[..3]` at `q`.
+    let (q, b) = (shift / 64, (shift % 64) as u32);
+    let (x0, x1) = (x as u64, (x >> 64) as u64);
+    let xs = if b == 0 {
+        [x0, x1, 0]
+    } else {
+        [x0 << b, x1 << b | x0 >> (64 - b), x1 >> (64 - b)]
+    };
+    for i in (0..core::cmp::max(used, q + 3)).rev() {
+        let pi = if i < used { p[i] } else { 0 };
+        let xi = if i >= q && i < q + 3 { xs[i - q] } else { 0 };
+        if pi != xi {
+            return pi < xi;
+        }
+    }
+    true
+}
+
+/// Returns `⌊ⁿ√a⌋` for `n ≥ 2` and `2¹²⁸ ≤ a < 2²⁵⁶`, given as little-endian
+/// limbs with a bit length of `len`.
+fn root_256(a: &[u64; 4], n: u32, len: u32) -> u128 {
+    // The root is less than 2¹²⁸, so find it a bit at a time from the top,
+    // keeping each bit for which the root's power doesn't exceed `a`.
+    let pow_le = |r: u128| {
+        let mut p = [1, 0, 0, 0];
+        for _ in 0..n {
+            let mut q = [0u64; 6];
+            for (i, &pi) in p.iter().enumerate() {
+                let mut carry = 0;
+                for (j, &rj) in [r as u64, (r >> 64) as u64].iter().enumerate() {
+                    let t = u128::from(pi) * u128::from(rj) + u128::from(q[i + j]) + carry;
+                    q[i + j] = t as u64;
+                    carry = t >> 64;
+                }
+                q[i + 2] = carry as u64;
+            }
+            if q[4] != 0 || q[5] != 0 {
+                return false;
+            }
+            p.copy_from_slice(&q[..4]);
+        }
+        p.iter().rev().le(a.iter().rev())
+    };
+
+    let mut r = 0u128;
+    for bit in (0..(len + n - 1) / n).rev() {
+        let c = r | 1 << bit;
+        if pow_le(c) {
+            r = c;
+        }
+    }
+    r
+}
+
+/// Returns `⌊√(Σ xᵢ²)⌋` for magnitudes `xᵢ`, or `None` if that's more than `max`.
+///
+/// The squares are summed in 256 bits, which is enough for any result that
+/// fits in a `u128`.
+fn norm_u128<I: Iterator<Item = u128>>(xs: I, max: u128) -> Option<u128> {
+    let (mut hi, mut lo) = (0u128, 0u128);
+    for x in xs {
+        // x² = xh²·2¹²⁸ + 2·xh·xl·2⁶⁴ + xl²
+        let (xh, xl) = (x >> 64, x & u128::from(core::u64::MAX));
+        let mid = xh * xl;
+        let (sum, c1) = lo.overflowing_add(xl * xl);
+        let (sum, c2) = sum.overflowing_add(mid << 65);
+        lo = sum;
+        hi = hi
+            .checked_add(xh * xh)?
+            .checked_add(mid >> 63)?
+            .checked_add(u128::from(c1) + u128::from(c2))?;
+    }
+
+    let r = if hi == 0 {
+        lo.sqrt()
+    } else {
+        let a = [lo as u64, (lo >> 64) as u64, hi as u64, (hi >> 64) as u64];
+        root_256(&a, 2, 256 - hi.leading_zeros())
+    };
+    if r <= max {
+        Some(r)
+    } else {
+        None
+    }
+}
+
 #[inline]
 fn bits<T>() -> u32 {
     8 * mem::size_of::<T>() as u32
 }
 
+/// Returns an approximation of `√x` for `x > 0`, to seed Newton's method.
+///
+/// This looks up the square root of the seven bits after the leading one,
+/// for either parity of `log₂ x`, in 16-bit fixed point, and scales it by half
+/// of `log₂ x`.  It rounds up, so the result is
+/// never less than the root, and within about 0.4% of it.
+#[cfg(not(feature = "std"))]
+#[inline]
+fn sqrt_guess(x: u64) -> u64 {
+    // `SQRT[p·128 + k] = ⌈√((1 + (k + 1)/128) · 2ᵖ) · 2¹⁵⌉`
+    static SQRT: [u32; 256] = [
+        32896, 33024, 33150, 33277, 33402, 33528, 33653, 33777, 33901, 34024, 34147, 34270, 34392,
+        34514, 34635, 34756, 34877, 34997, 35116, 35236, 35354, 35473, 35591, 35709, 35826, 35943,
+        36059, 36175, 36291, 36407, 36522, 36636, 36751, 36864, 36978, 37091, 37204, 37317, 37429,
+        37541, 37653, 37764, 37875, 37985, 38096, 38205, 38315, 38424, 38533, 38642, 38750, 38859,
+        38966, 39074, 39181, 39288, 39395, 39501, 39607, 39713, 39818, 39923, 40028, 40133, 40237,
+        40341, 40445, 40549, 40652, 40755, 40858, 40960, 41063, 41165, 41267, 41368, 41469, 41570,
+        41671, 41772, 41872, 41972, 42072, 42171, 42271, 42370, 42469, 42567, 42666, 42764, 42862,
+        42960, 43057, 43155, 43252, 43348, 43445, 43542, 43638, 43734, 43830, 43925, 44021, 44116,
+        44211, 44306, 44400, 44494, 44589, 44683, 44776, 44870, 44963, 45056, 45149, 45242, 45335,
+        45427, 45520, 45612, 45703, 45795, 45887, 45978, 46069, 46160, 46251, 46341, 46522, 46702,
+        46881, 47060, 47238, 47415, 47592, 47768, 47943, 48118, 48292, 48465, 48638, 48810, 48982,
+        49152, 49323, 49493, 49662, 49830, 49999, 50166, 50333, 50499, 50665, 50831, 50995, 51160,
+        51323, 51486, 51649, 51811, 51973, 52134, 52295, 52455, 52615, 52774, 52932, 53091, 53248,
+        53406, 53563, 53719, 53875, 54030, 54185, 54340, 54494, 54648, 54801, 54954, 55107, 55259,
+        55410, 55561, 55712, 55862, 56012, 56162, 56311, 56460, 56608, 56756, 56904, 57051, 57198,
+        57344, 57491, 57636, 57782, 57927, 58071, 58216, 58360, 58503, 58646, 58789, 58932, 59074,
+        59216, 59357, 59498, 59639, 59780, 59920, 60060, 60199, 60338, 60477, 60616, 60754, 60892,
+        61030, 61167, 61304, 61440, 61577, 61713, 61849, 61984, 62119, 62254, 62389, 62523, 62657,
+        62791, 62924, 63058, 63191, 63323, 63455, 63588, 63719, 63851, 63982, 64113, 64244, 64374,
+        64504, 64634, 64764, 64893, 65022, 65151, 65280, 65408, 65536,
+    ];
+
+    // Shift the leading one to the top, for the seven bits after it.
+    let lz = x.leading_zeros();
+    let top = (x << lz) >> 56;
+    let k = ((!lz & 1) << 7) as usize | (top as usize & 127);
+    let s = u64::from(SQRT[k]) << ((63 - lz) / 2);
+    (s + (1 << 15) - 1) >> 15
+}
+
+/// Returns an approximation of `ⁿ√x` for `x > 0` and `n ≥ 3`, to seed Newton's
+/// method.
+///
+/// This looks up `log₂ x` by its leading bits, divides that by `n`, and looks
+/// up its power of two again, all in 16-bit fixed point.  Every step rounds
+/// up, so the result is never less than the root, and within about 2% of it.
+/// It doesn't use any floating point, so it's the same with or without `std`.
 #[inline]
-fn log2<T: PrimInt>(x: T) -> u32 {
-    debug_assert!(x > T::zero());
-    bits::<T>() - 1 - x.leading_zeros()
+fn guess(x: u64, n: u32) -> u64 {
+    // `LOG2[k] = ⌈log₂(1 + (k + 1)/128) · 2¹⁶⌉`
+    static LOG2: [u32; 128] = [
+        736, 1466, 2191, 2910, 3623, 4332, 5035, 5732, 6425, 7113, 7795, 8473, 9146, 9814, 10478,
+        11137, 11791, 12441, 13086, 13727, 14364, 14996, 15625, 16249, 16869, 17485, 18097, 18705,
+        19309, 19909, 20506, 21098, 21687, 22273, 22855, 23433, 24008, 24579, 25147, 25711, 26273,
+        26830, 27385, 27936, 28484, 29029, 29571, 30110, 30645, 31178, 31708, 32235, 32758, 33279,
+        33797, 34313, 34825, 35335, 35842, 36346, 36848, 37347, 37843, 38337, 38828, 39316, 39802,
+        40286, 40767, 41246, 41722, 42196, 42668, 43137, 43604, 44069, 44531, 44991, 45449, 45905,
+        46358, 46809, 47258, 47705, 48150, 48593, 49034, 49473, 49910, 50344, 50777, 51208, 51636,
+        52063, 52488, 52911, 53332, 53752, 54169, 54585, 54999, 55411, 55821, 56229, 56636, 57041,
+        57444, 57845, 58245, 58643, 59040, 59434, 59828, 60219, 60609, 60997, 61384, 61769, 62153,
+        62535, 62915, 63294, 63672, 64048, 64422, 64795, 65166, 65536,
+    ];
+    // `EXP2[k] = ⌈2^((k + 1)/128) · 2¹⁵⌉`
+    static EXP2: [u32; 128] = [
+        32946, 33125, 33305, 33486, 33668, 33851, 34034, 34219, 34405, 34592, 34780, 34969, 35158,
+        35349, 35541, 35734, 35928, 36123, 36320, 36517, 36715, 36914, 37115, 37316, 37519, 37723,
+        37927, 38133, 38340, 38549, 38758, 38968, 39180, 39393, 39607, 39822, 40038, 40255, 40474,
+        40694, 40915, 41137, 41360, 41585, 41811, 42038, 42266, 42495, 42726, 42958, 43191, 43426,
+        43662, 43899, 44137, 44377, 44618, 44860, 45104, 45348, 45595, 45842, 46091, 46341, 46593,
+        46846, 47100, 47356, 47613, 47872, 48132, 48393, 48656, 48920, 49186, 49453, 49721, 49991,
+        50263, 50536, 50810, 51086, 51363, 51642, 51923, 52205, 52488, 52773, 53060, 53348, 53637,
+        53929, 54221, 54516, 54812, 55109, 55409, 55710, 56012, 56316, 56622, 56929, 57239, 57549,
+        57862, 58176, 58492, 58810, 59129, 59450, 59773, 60097, 60424, 60752, 61082, 61413, 61747,
+        62082, 62419, 62758, 63099, 63441, 63786, 64132, 64480, 64831, 65183, 65536,
+    ];
+
+    // The seven bits following the leading one, as an index.
+    let lg = Logarithms::ilog2(&x);
+    let top = if lg >= 7 {
+        x >> (lg - 7)
+    } else {
+        x << (7 - lg)
+    };
+    let log = (lg << 16) + LOG2[top as usize & 127];
+    let log = (log + n - 1) / n;
+
+    let (q, e) = (log >> 16, u64::from(EXP2[(log >> 9) as usize & 127]));
+    if q >= 15 {
+        e << (q - 15)
+    } else {
+        (e + (1 << (15 - q)) - 1) >> (15 - q)
+    }
 }
 
 macro_rules! unsigned_roots {
@@ -201,10 +1435,17 @@ macro_rules! unsigned_roots {
         impl Roots for $T {
             #[inline]
             fn nth_root(&self, n: u32) -> Self {
+                match self.checked_nth_root(n) {
+                    Some(r) => r,
+                    None => panic!("can't find a root of degree 0!"),
+                }
+            }
+
+            #[inline]
+            fn checked_nth_root(&self, n: u32) -> Option<Self> {
                 fn go(a: $T, n: u32) -> $T {
                     // Specialize small roots
                     match n {
-                        0 => panic!("can't find a root of degree 0!"),
                         1 => return a,
                         2 => return a.sqrt(),
                         3 => return a.cbrt(),
@@ -239,36 +1480,26 @@ macro_rules! unsigned_roots {
                             }
                         };
                     }
-
-                    #[cfg(feature = "std")]
-                    #[inline]
-                    fn guess(x: $T, n: u32) -> $T {
-                        // for smaller inputs, `f64` doesn't justify its cost.
-                        if bits::<$T>() <= 32 || x <= core::u32::MAX as $T {
-                            1 << ((log2(x) + n - 1) / n)
-                        } else {
-                            ((x as f64).ln() / f64::from(n)).exp() as $T
-                        }
-                    }
-
-                    #[cfg(not(feature = "std"))]
-                    #[inline]
-                    fn guess(x: $T, n: u32) -> $T {
-                        1 << ((log2(x) + n - 1) / n)
+                    if bits::<$T>() > 32 && a <= core::u32::MAX as $T {
+                        return (a as u32).nth_root(n) as $T;
                     }
 
                     // https://en.wikipedia.org/wiki/Nth_root_algorithm
                     let n1 = n - 1;
-                    let next = |x: $T| {
+                    let next = |&x: &$T| {
                         let y = match checked_pow(x, n1 as usize) {
                             Some(ax) => a / ax,
                             None => 0,
                         };
                         (y + x * n1 as $T) / n as $T
                     };
-                    fixpoint(guess(a, n), next)
+                    fixpoint(guess(a as u64, n) as $T, next)
+                }
+                if n == 0 {
+                    None
+                } else {
+                    Some(go(*self, n))
                 }
-                go(*self, n)
             }
 
             #[inline]
@@ -293,21 +1524,45 @@ macro_rules! unsigned_roots {
                         return (a > 0) as $T;
                     }
 
+                    if bits::<$T>() > 32 && a <= core::u32::MAX as $T {
+                        return (a as u32).sqrt() as $T;
+                    }
+
                     #[cfg(feature = "std")]
                     #[inline]
-                    fn guess(x: $T) -> $T {
-                        (x as f64).sqrt() as $T
+                    fn wide(a: $T) -> $T {
+                        // `f64::sqrt` is correctly rounded, so truncating it is within one
+                        // of the root, and correcting that needs no division at all.
+                        let x = (a as f64).sqrt() as $T;
+                        if x.checked_mul(x).map_or(true, |x2| x2 > a) {
+                            x - 1
+                        } else if (x + 1).checked_mul(x + 1).map_or(false, |x2| x2 <= a) {
+                            x + 1
+                        } else {
+                            x
+                        }
                     }
 
                     #[cfg(not(feature = "std"))]
                     #[inline]
-                    fn guess(x: $T) -> $T {
-                        1 << ((log2(x) + 1) / 2)
+                    fn wide(a: $T) -> $T {
+                        // The guess is never too small, so descend until the square fits, which
+                        // saves the division that `fixpoint` would spend confirming the root.
+                        let mut x = sqrt_guess(a as u64) as $T;
+                        while x.checked_mul(x).map_or(true, |x2| x2 > a) {
+                            x = (a / x + x) >> 1;
+                        }
+                        x
                     }
 
-                    // https://en.wikipedia.org/wiki/Methods_of_computing_square_roots#Babylonian_method
-                    let next = |x: $T| (a / x + x) >> 1;
-                    fixpoint(guess(a), next)
+                    if bits::<$T>() <= 32 {
+                        // A table seed saves less than one division here, which doesn't pay
+                        // for itself, while dividing by a power of two is just a shift.
+                        // https://en.wikipedia.org/wiki/Methods_of_computing_square_roots#Babylonian_method
+                        let next = |&x: &$T| (a / x + x) >> 1;
+                        return fixpoint(1 << ((Logarithms::ilog2(&a) + 1) / 2), next);
+                    }
+                    wide(a)
                 }
                 go(*self)
             }
@@ -357,24 +1612,125 @@ macro_rules! unsigned_roots {
                         return (a as u32).cbrt() as $T;
                     }
 
-                    #[cfg(feature = "std")]
-                    #[inline]
-                    fn guess(x: $T) -> $T {
-                        (x as f64).cbrt() as $T
-                    }
-
-                    #[cfg(not(feature = "std"))]
-                    #[inline]
-                    fn guess(x: $T) -> $T {
-                        1 << ((log2(x) + 2) / 3)
-                    }
-
                     // https://en.wikipedia.org/wiki/Cube_root#Numerical_methods
-                    let next = |x: $T| (a / (x * x) + x * 2) / 3;
-                    fixpoint(guess(a), next)
+                    let next = |&x: &$T| (a / (x * x) + x * 2) / 3;
+                    fixpoint(guess(a as u64, 3) as $T, next)
                 }
                 go(*self)
             }
+
+            #[inline]
+            fn checked_sqrt(&self) -> Option<Self> {
+                Some(self.sqrt())
+            }
+
+            #[inline]
+            fn checked_cbrt(&self) -> Option<Self> {
+                Some(self.cbrt())
+            }
+
+            #[inline]
+            fn is_perfect_square(&self) -> bool {
+                self.exact_sqrt().is_some()
+            }
+
+            #[inline]
+            fn exact_sqrt(&self) -> Option<Self> {
+                let a = *self;
+                let a_mod_45045 = if bits::<$T>() > 64 {
+                    (a as u128 % 45045) as u32
+                } else {
+                    (a as u64 % 45045) as u32
+                };
+                if !is_square_residue(a as u32 & 63, a_mod_45045) {
+                    return None;
+                }
+                let r = a.sqrt();
+                if r * r == a {
+                    Some(r)
+                } else {
+                    None
+                }
+            }
+
+            #[inline]
+            fn exact_nth_root(&self, n: u32) -> Option<Self> {
+                if n == 2 {
+                    return self.exact_sqrt();
+                }
+                // The truncated root's power is no greater than `self`, so
+                // this `pow` can't overflow.
+                let r = self.checked_nth_root(n)?;
+                if r.pow(n) == *self {
+                    Some(r)
+                } else {
+                    None
+                }
+            }
+
+            #[inline]
+            fn exact_cbrt(&self) -> Option<Self> {
+                let r = self.cbrt();
+                if r * r * r == *self {
+                    Some(r)
+                } else {
+                    None
+                }
+            }
+
+            #[inline]
+            fn nth_root_rem(&self, n: u32) -> (Self, Self) {
+                let r = self.nth_root(n);
+                (r, *self - r.pow(n))
+            }
+
+            #[inline]
+            fn sqrt_rem(&self) -> (Self, Self) {
+                let r = self.sqrt();
+                (r, *self - r * r)
+            }
+
+            #[inline]
+            fn hypot(&self, other: &Self) -> Self {
+                Self::norm(&[*self, *other])
+            }
+
+            #[inline]
+            fn norm(v: &[Self]) -> Self {
+                let xs = v.iter().map(|&x| x as u128);
+                match norm_u128(xs, Self::max_value() as u128) {
+                    Some(r) => r as Self,
+                    None => panic!("the norm overflows the integer type"),
+                }
+            }
+
+            #[inline]
+            fn nth_root_scaled(&self, n: u32, frac_bits: u32) -> Option<Self> {
+                if n == 0 {
+                    panic!("can't find a root of degree 0!");
+                }
+                if *self == 0 {
+                    return Some(0);
+                }
+                let r = scaled_root(*self as u128, n, frac_bits)?;
+                if r <= Self::max_value() as u128 {
+                    Some(r as Self)
+                } else {
+                    None
+                }
+            }
+
+            #[inline]
+            fn nth_root_rounded(&self, n: u32, rounding: Rounding) -> Self {
+                let (r, rem) = self.nth_root_rem(n);
+                let up = rem > 0
+                    && match rounding {
+                        Rounding::Floor | Rounding::TowardZero => false,
+                        Rounding::Ceiling | Rounding::AwayFromZero => true,
+                        Rounding::Nearest => rounds_up(*self as u128, r as u128, n),
+                    };
+                r + up as $T
+            }
         }
     };
 }
@@ -0,0 +1,167 @@
+use alloc::vec::Vec;
+
+/// A table of the smallest prime factor of every number up to a limit.
+///
+/// This is built with a linear sieve in `O(n)` time, after which numbers in
+/// range can be factored in `O(log n)`, faster than any general factorization.
+///
+/// # Examples
+///
+/// ```
+/// use num_integer::SpfSieve;
+///
+/// let sieve = SpfSieve::new(100);
+/// assert_eq!(sieve.primes().len(), 25);
+/// assert_eq!(sieve.smallest_prime_factor(91), Some(7));
+/// assert!(sieve.is_prime(97));
+///
+/// let factors: Vec<(u32, u32)> = sieve.factors(72).collect();
+/// assert_eq!(factors, [(2, 3), (3, 2)]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct SpfSieve {
+    spf: Vec<u32>,
+    primes: Vec<u32>,
+}
+
+impl SpfSieve {
+    /// Sieves the smallest prime factors of all numbers up to and including `n`.
+    pub fn new(n: u32) -> SpfSieve {
+        let len = n as usize + 1;
+        let mut spf = alloc::vec![0; len];
+        let mut primes = Vec::new();
+
+        // Each composite is crossed off exactly once, by its smallest prime factor.
+        for i in 2..len {
+            if spf[i] == 0 {
+                spf[i] = i as u32;
+                primes.push(i as u32);
+            }
+            let spf_i = spf[i];
+            for &p in &primes {
+                if p > spf_i {
+                    break;
+                }
+                let m = i as u64 * u64::from(p);
+                if m >= len as u64 {
+                    break;
+                }
+                spf[m as usize] = p;
+            }
+        }
+
+        SpfSieve { spf, primes }
+    }
+
+    /// Returns the largest number covered by the sieve.
+    #[inline]
+    pub fn limit(&self) -> u32 {
+        (self.spf.len() - 1) as u32
+    }
+
+    /// Returns all of the primes up to the limit, in increasing order.
+    #[inline]
+    pub fn primes(&self) -> &[u32] {
+        &self.primes
+    }
+
+    /// Returns the smallest prime factor of `m`, or `None` for `0` and `1`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `m` is greater than the limit.
+    #[inline]
+    pub fn smallest_prime_factor(&self, m: u32) -> Option<u32> {
+        match self.spf[self.index(m)] {
+            0 => None,
+            p => Some(p),
+        }
+    }
+
+    /// Returns `true` if `m` is prime.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `m` is greater than the limit.
+    #[inline]
+    pub fn is_prime(&self, m: u32) -> bool {
+        m > 1 && self.spf[self.index(m)] == m
+    }
+
+    /// Returns an iterator over the distinct prime factors of `m` and their
+    /// exponents, in increasing order of the primes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `m` is zero or greater than the limit.
+    pub fn factors(&self, m: u32) -> SpfFactors<'_> {
+        assert!(m != 0, "zero has no prime factorization");
+        self.index(m);
+        SpfFactors { sieve: self, m }
+    }
+
+    /// Returns Euler's totient of `m` -- `φ(m)`, the count of numbers in
+    /// `1..=m` that are coprime to `m`.  By convention, `φ(0) = 0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `m` is greater than the limit.
+    pub fn totient(&self, m: u32) -> u32 {
+        if m == 0 {
+            self.index(m);
+            return 0;
+        }
+        self.factors(m).fold(m, |phi, (p, _)| phi / p * (p - 1))
+    }
+
+    /// Returns the Möbius function of `m` -- see [`mobius`](fn.mobius.html).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `m` is greater than the limit.
+    pub fn mobius(&self, m: u32) -> i8 {
+        if m == 0 {
+            self.index(m);
+            return 0;
+        }
+        let mut mu = 1;
+        for (_, e) in self.factors(m) {
+            if e > 1 {
+                return 0;
+            }
+            mu = -mu;
+        }
+        mu
+    }
+
+    #[inline]
+    fn index(&self, m: u32) -> usize {
+        let i = m as usize;
+        assert!(i < self.spf.len(), "{} is beyond the sieve limit", m);
+        i
+    }
+}
+
+/// An iterator over the prime factors of a number, from an [`SpfSieve`](struct.SpfSieve.html).
+#[derive(Clone, Debug)]
+pub struct SpfFactors<'a> {
+    sieve: &'a SpfSieve,
+    m: u32,
+}
+
+impl<'a> Iterator for SpfFactors<'a> {
+    type Item = (u32, u32);
+
+    fn next(&mut self) -> Option<(u32, u32)> {
+        if self.m <= 1 {
+            return None;
+        }
+        let p = self.sieve.spf[self.m as usize];
+        let mut e = 0;
+        while self.m % p == 0 {
+            self.m /= p;
+            e += 1;
+        }
+        Some((p, e))
+    }
+}
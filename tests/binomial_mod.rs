@@ -0,0 +1,69 @@
+use num_integer::{binomial_mod, checked_binomial};
+
+#[test]
+fn small() {
+    // Every modulus up to 300 against the exact value.
+    for n in 0..70u64 {
+        for k in 0..=n + 1 {
+            let exact = checked_binomial(u128::from(n), u128::from(k)).unwrap();
+            for m in 1..=300 {
+                let expected = (exact % u128::from(m)) as u64;
+                assert_eq!(binomial_mod(n, k, m), expected, "C({}, {}) mod {}", n, k, m);
+            }
+        }
+    }
+}
+
+#[test]
+fn wide_modulus() {
+    // Exact values of `C(n, k) mod m`, computed with a bigint.
+    let cases = [
+        (2_000, 1_000, 1_000_000_000, 991_149_120),
+        (2_000, 1_000, u64::max_value(), 14_109_491_319_577_467_490),
+        (3_000, 1_234, 1 << 60, 366_139_680_865_278_752),
+        (4_321, 1_234, 1 << 63, 2_043_476_279_106_820_800),
+        (5_000, 2_500, 6_973_568_802, 4_599_923_634), // 2·3²⁰
+        (1_000, 500, 4_294_967_291, 161_065_921),
+        (1_000, 500, 40_411_347_241_019, 29_047_024_854_954), // (2³²-5)·97²
+        (
+            100_000,
+            30_000,
+            1_000_000_000_000_000_000,
+            279_769_789_615_184_000,
+        ),
+        (100_000, 50_000, 27_286_875, 3_711_015), // 5⁴·3⁴·7²·11
+    ];
+    for &(n, k, m, expected) in &cases {
+        assert_eq!(binomial_mod(n, k, m), expected, "C({}, {}) mod {}", n, k, m);
+        assert_eq!(binomial_mod(n, n - k, m), expected);
+    }
+}
+
+#[test]
+fn large_n() {
+    let n = 1_000_000_000_000_000_000;
+    let p = 1_000_000_007;
+    assert_eq!(binomial_mod(n, 0, p), 1);
+    assert_eq!(binomial_mod(n, n, p), 1);
+    assert_eq!(binomial_mod(n, 1, p), n % p);
+    assert_eq!(binomial_mod(n, 2, p), 1176);
+    assert_eq!(binomial_mod(n, 123_456, p), 0);
+    assert_eq!(binomial_mod(n, 3_000_000_041, p), 796_877_352);
+    assert_eq!(binomial_mod(n, 3_000_000_041, 998_244_353), 508_500_512);
+    assert_eq!(binomial_mod(n, 1_000_000_000, 998_244_353), 185_830_559);
+    assert_eq!(binomial_mod(n, 12345, 59_049), 26_973);
+
+    // Kummer: C(2ʲ - 1, k) is odd for every k.
+    let n = u64::max_value();
+    for &k in &[0, 1, 2, 12345, 1 << 40, n / 3, n] {
+        assert_eq!(binomial_mod(n, k, 2), 1);
+    }
+    assert_eq!(binomial_mod(n, 2, 1), 0);
+    assert_eq!(binomial_mod(n - 1, n / 2, 2), 0);
+}
+
+#[test]
+#[should_panic]
+fn zero_modulus() {
+    binomial_mod(10, 3, 0);
+}
@@ -0,0 +1,156 @@
+use num_integer::Digits;
+
+/// Formats the magnitude of `x` in `base`, most significant digit first.
+fn naive(mut x: u128, base: u128) -> Vec<u128> {
+    let mut v = vec![x % base];
+    x /= base;
+    while x > 0 {
+        v.push(x % base);
+        x /= base;
+    }
+    v.reverse();
+    v
+}
+
+macro_rules! test_digits {
+    ($I:ident, $U:ident) => {
+        mod $I {
+            use crate::naive;
+            use num_integer::Digits;
+
+            fn check(x: $I, base: $I) {
+                let magnitude = (x as i128).wrapping_abs() as u128;
+                let expected = naive(magnitude, base as u128);
+                let msd: Vec<u128> = x.digits(&base).rev().map(|d| d as u128).collect();
+                assert_eq!(msd, expected, "{} in base {}", x, base);
+                let mut lsd: Vec<u128> = x.digits(&base).map(|d| d as u128).collect();
+                lsd.reverse();
+                assert_eq!(lsd, expected);
+                assert_eq!(x.digits(&base).len(), expected.len());
+                assert_eq!(x.digit_count(&base) as usize, expected.len());
+                assert_eq!(x.digit_sum(&base) as u128, expected.iter().sum::<u128>());
+
+                let palindrome = expected.iter().eq(expected.iter().rev());
+                assert_eq!(x.is_palindrome(&base), palindrome);
+
+                if x >= 0 {
+                    assert_eq!($I::from_digits(x.digits(&base).rev(), &base), Some(x));
+                }
+                let reversed = expected.iter().rev().fold(Some(0 as $I), |r, &d| {
+                    let r = r?.checked_mul(base)?;
+                    if x < 0 {
+                        r.checked_sub(d as $I)
+                    } else {
+                        r.checked_add(d as $I)
+                    }
+                });
+                assert_eq!(x.reverse_digits(&base), reversed);
+
+                let u = x as $U;
+                let expected = naive(u as u128, base as u128);
+                assert_eq!(u.digit_count(&(base as $U)) as usize, expected.len());
+                assert_eq!(u.digits(&(base as $U)).len(), expected.len());
+            }
+
+            fn values() -> Vec<$I> {
+                let (min, max) = (std::$I::MIN, std::$I::MAX);
+                let mut v: Vec<$I> = (-300..300)
+                    .filter(|&x| x as $I as i32 == x)
+                    .map(|x| x as $I)
+                    .collect();
+                v.extend(&[min, min + 1, max, max - 1, max / 7, min / 10]);
+                v
+            }
+
+            #[test]
+            fn exhaustive_bases() {
+                for x in values() {
+                    for base in (2..40).chain(vec![std::$I::MAX]) {
+                        check(x, base);
+                    }
+                }
+            }
+
+            #[test]
+            fn interleaved() {
+                // Alternate between the two ends of the iterator.
+                for x in values() {
+                    let mut d = x.digits(&10);
+                    let mut front = Vec::new();
+                    let mut back = Vec::new();
+                    loop {
+                        match d.next() {
+                            Some(f) => front.push(f),
+                            None => break,
+                        }
+                        match d.next_back() {
+                            Some(b) => back.push(b),
+                            None => break,
+                        }
+                    }
+                    assert_eq!(d.next(), None);
+                    assert_eq!(d.next_back(), None);
+                    back.extend(front.into_iter().rev());
+                    assert_eq!(back, x.digits(&10).rev().collect::<Vec<_>>());
+                }
+            }
+
+            #[test]
+            #[should_panic]
+            fn base_one() {
+                (5 as $I).digits(&1);
+            }
+
+            #[test]
+            #[should_panic]
+            fn count_base_zero() {
+                (5 as $U).digit_count(&0);
+            }
+        }
+    };
+}
+
+test_digits!(i8, u8);
+test_digits!(i16, u16);
+test_digits!(i32, u32);
+test_digits!(i64, u64);
+test_digits!(i128, u128);
+test_digits!(isize, usize);
+
+#[test]
+fn formatted() {
+    for &x in &[0u64, 1, 9, 10, 12345, 1 << 40, u64::max_value()] {
+        let s: String = x
+            .digits(&10)
+            .rev()
+            .map(|d| (b'0' + d as u8) as char)
+            .collect();
+        assert_eq!(s, x.to_string());
+        assert_eq!(x.digit_count(&16) as usize, format!("{:x}", x).len());
+        assert_eq!(x.digit_count(&8) as usize, format!("{:o}", x).len());
+    }
+}
+
+#[test]
+fn from_digits() {
+    assert_eq!(u8::from_digits(vec![], &10), Some(0));
+    assert_eq!(u8::from_digits(vec![0, 0, 2, 5, 5], &10), Some(255));
+    assert_eq!(u8::from_digits(vec![1, 0, 0, 0, 0, 0, 0, 0, 0], &2), None);
+    assert_eq!(u8::from_digits(vec![1, 10], &10), None);
+    assert_eq!(i8::from_digits(vec![1, 2, 8], &10), None);
+    assert_eq!(i8::from_digits(vec![1, 2, 7], &10), Some(127));
+    assert_eq!(
+        u128::from_digits(u128::max_value().digits(&3).rev(), &3),
+        Some(u128::max_value())
+    );
+}
+
+#[test]
+fn reverse() {
+    assert_eq!(i8::min_value().reverse_digits(&10), None);
+    assert_eq!((-21i8).reverse_digits(&10), Some(-12));
+    assert_eq!(0u8.reverse_digits(&10), Some(0));
+    assert_eq!(1000u32.reverse_digits(&10), Some(1));
+    assert_eq!(u64::max_value().reverse_digits(&2), Some(u64::max_value()));
+    assert_eq!((1u64 << 63).reverse_digits(&2), Some(1));
+}
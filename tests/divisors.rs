@@ -0,0 +1,62 @@
+use num_integer::{divisor_count, divisor_sigma, divisor_sum, divisors, factorize};
+
+/// Returns the divisors of `n` by trial division, in increasing order.
+fn naive_divisors(n: u64) -> Vec<u64> {
+    (1..=n).filter(|d| n % d == 0).collect()
+}
+
+#[test]
+fn divisors_small() {
+    for n in 1..=2_000 {
+        let expected = naive_divisors(n);
+
+        let mut actual: Vec<u64> = divisors(n).collect();
+        actual.sort();
+        assert_eq!(actual, expected);
+
+        assert_eq!(divisor_count(n), expected.len() as u64);
+        assert_eq!(
+            divisor_sum(n),
+            expected.iter().map(|&d| u128::from(d)).sum()
+        );
+        for k in 0..4 {
+            let sigma = expected.iter().map(|&d| u128::from(d).pow(k)).sum();
+            assert_eq!(divisor_sigma(n, k), Some(sigma));
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn sorted_divisors() {
+    for n in 1..=2_000 {
+        assert_eq!(num_integer::sorted_divisors(n), naive_divisors(n));
+    }
+}
+
+#[test]
+fn divisors_large() {
+    // 2⁷·3⁴·5²·7²·11·13·17·19·23·29·31·37·41, the most divisors of any `u64`
+    let n = 18_401_055_938_125_660_800;
+    assert_eq!(divisor_count(n), 184_320);
+    assert_eq!(divisors(n).count(), 184_320);
+    assert!(divisors(n).all(|d| n % d == 0));
+    assert_eq!(factorize(n).divisors().max(), Some(n));
+
+    let p = 18_446_744_073_709_551_557;
+    let mut d: Vec<u64> = divisors(p).collect();
+    d.sort();
+    assert_eq!(d, [1, p]);
+    assert_eq!(divisor_sum(p), u128::from(p) + 1);
+    assert_eq!(divisor_sigma(p, 2), Some(u128::from(p).pow(2) + 1));
+    assert_eq!(divisor_sigma(p, 3), None);
+}
+
+#[test]
+fn divisor_sum_max() {
+    let n = std::u64::MAX;
+    let sum: u128 = divisors(n).map(u128::from).sum();
+    assert_eq!(divisor_sum(n), sum);
+    assert_eq!(divisor_sigma(n, 1), Some(sum));
+    assert_eq!(divisor_sigma(n, 0), Some(u128::from(divisor_count(n))));
+}
@@ -0,0 +1,134 @@
+use num_integer::{checked_fibonacci, checked_lucas, fibonacci, fibonacci_mod, lucas, Fibonacci};
+
+/// Returns the first `n` terms of the sequence starting `a, b`, in `u128`,
+/// stopping early at overflow.
+fn naive(mut a: u128, mut b: u128, n: usize) -> Vec<u128> {
+    let mut v = Vec::new();
+    for _ in 0..n {
+        v.push(a);
+        match a.checked_add(b) {
+            Some(c) => {
+                a = b;
+                b = c;
+            }
+            None => {
+                v.push(b);
+                break;
+            }
+        }
+    }
+    v
+}
+
+macro_rules! test_fibonacci {
+    ($T:ident, $fib:expr, $luc:expr) => {
+        mod $T {
+            use crate::naive;
+            use num_integer::{
+                checked_fibonacci, checked_lucas, fibonacci, fibonacci_mod, lucas, Fibonacci,
+            };
+
+            const MAX_FIB: u64 = $fib;
+            const MAX_LUC: u64 = $luc;
+
+            #[test]
+            fn limits() {
+                let max = $T::max_value() as u128;
+                let f = naive(0, 1, 200);
+                for n in 0..=MAX_FIB {
+                    assert_eq!(fibonacci::<$T>(n) as u128, f[n as usize]);
+                    assert_eq!(checked_fibonacci::<$T>(n), Some(f[n as usize] as $T));
+                }
+                assert!(f[MAX_FIB as usize + 1] > max);
+                for n in MAX_FIB + 1..MAX_FIB + 200 {
+                    assert_eq!(checked_fibonacci::<$T>(n), None);
+                }
+
+                let l = naive(2, 1, 200);
+                for n in 0..=MAX_LUC {
+                    assert_eq!(lucas::<$T>(n) as u128, l[n as usize]);
+                    assert_eq!(checked_lucas::<$T>(n), Some(l[n as usize] as $T));
+                }
+                assert!(l[MAX_LUC as usize + 1] > max);
+                for n in MAX_LUC + 1..MAX_LUC + 200 {
+                    assert_eq!(checked_lucas::<$T>(n), None);
+                }
+                assert_eq!(checked_lucas::<$T>(u64::max_value()), None);
+            }
+
+            #[test]
+            fn iterator() {
+                let f: Vec<u128> = Fibonacci::<$T>::new().map(|x| x as u128).collect();
+                assert_eq!(f, &naive(0, 1, 200)[..=MAX_FIB as usize]);
+                let l: Vec<u128> = Fibonacci::<$T>::lucas().map(|x| x as u128).collect();
+                assert_eq!(l, &naive(2, 1, 200)[..=MAX_LUC as usize]);
+            }
+
+            #[test]
+            fn modulus() {
+                let max = $T::max_value();
+                for &m in &[1, 2, 3, 10, 97, max / 3, max - 1, max] {
+                    let mut a = 0u128;
+                    let mut b = 1u128;
+                    let m128 = m as u128;
+                    for n in 0..300 {
+                        assert_eq!(fibonacci_mod(n, m) as u128, a % m128, "F({}) mod {}", n, m);
+                        let c = (a + b % m128) % m128;
+                        a = b % m128;
+                        b = c;
+                    }
+                }
+            }
+
+            #[test]
+            #[should_panic]
+            fn modulus_zero() {
+                fibonacci_mod(5, 0 as $T);
+            }
+        }
+    };
+}
+
+test_fibonacci!(u8, 13, 11);
+test_fibonacci!(i8, 11, 10);
+test_fibonacci!(u16, 24, 23);
+test_fibonacci!(i16, 23, 21);
+test_fibonacci!(u32, 47, 46);
+test_fibonacci!(i32, 46, 44);
+test_fibonacci!(u64, 93, 92);
+test_fibonacci!(i64, 92, 90);
+test_fibonacci!(i128, 184, 182);
+
+#[test]
+fn u128_limits() {
+    // The terms past these overflow `u128` itself, so check them directly.
+    let f = naive(0, 1, 200);
+    assert_eq!(f.len(), 187);
+    assert_eq!(fibonacci::<u128>(186), f[186]);
+    assert_eq!(checked_fibonacci::<u128>(186), Some(f[186]));
+    assert_eq!(checked_fibonacci::<u128>(187), None);
+    assert_eq!(Fibonacci::<u128>::new().count(), 187);
+
+    let l = naive(2, 1, 200);
+    assert_eq!(lucas::<u128>(184), l[184]);
+    assert_eq!(checked_lucas::<u128>(184), Some(l[184]));
+    assert_eq!(checked_lucas::<u128>(185), None);
+    assert_eq!(Fibonacci::<u128>::lucas().count(), 185);
+}
+
+#[test]
+fn large_modulus() {
+    assert_eq!(fibonacci_mod(186, u128::max_value()), naive(0, 1, 200)[186]);
+    assert_eq!(fibonacci_mod(0, 7u8), 0);
+    assert_eq!(fibonacci_mod(u64::max_value(), 1u8), 0);
+    assert_eq!(
+        fibonacci_mod(1_000_000_000_000, 1_000_000_007i64),
+        730_695_249
+    );
+    // F(n) mod 2^64 - 1 agrees with u128 arithmetic.
+    let m = u64::max_value();
+    assert_eq!(
+        fibonacci_mod(150, m) as u128,
+        naive(0, 1, 200)[150] % m as u128
+    );
+}
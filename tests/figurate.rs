@@ -0,0 +1,170 @@
+use num_integer::{checked_pyramidal, is_triangular, polygonal_root, pyramidal_root};
+use num_integer::{triangular, triangular_root};
+
+macro_rules! test_figurate {
+    ($T:ident) => {
+        mod $T {
+            use num_integer::{checked_polygonal, checked_pyramidal, checked_triangular};
+            use num_integer::{is_polygonal, is_pyramidal, is_triangular};
+            use num_integer::{polygonal, pyramidal, triangular};
+            use num_integer::{polygonal_root, pyramidal_root, triangular_root};
+
+            /// The `s`-gonal and `s`-gonal pyramidal numbers that fit in `$T`,
+            /// by repeated addition in `u128`.
+            fn naive(s: u128) -> (Vec<u128>, Vec<u128>) {
+                let max = $T::max_value() as u128;
+                let (mut p, mut pyr) = (vec![0u128], vec![0u128]);
+                for n in 1u128.. {
+                    let x = p[p.len() - 1] + (s - 2) * (n - 1) + 1;
+                    if x > max {
+                        break;
+                    }
+                    p.push(x);
+                    let y = pyr[pyr.len() - 1] + x;
+                    if y <= max {
+                        pyr.push(y);
+                    }
+                }
+                (p, pyr)
+            }
+
+            /// The index of the last element of `v` that's at most `x`.
+            fn floor_index(v: &[u128], x: u128) -> usize {
+                match v.binary_search(&x) {
+                    Ok(i) => i,
+                    Err(i) => i - 1,
+                }
+            }
+
+            fn sides() -> Vec<$T> {
+                let mut v: Vec<$T> = (3..20).collect();
+                v.extend(&[$T::max_value() / 5, $T::max_value()]);
+                v
+            }
+
+            #[test]
+            fn forward() {
+                for s in sides() {
+                    let (p, pyr) = naive(s as u128);
+                    for (n, &x) in p.iter().enumerate() {
+                        let n = n as $T;
+                        assert_eq!(polygonal(s, n) as u128, x);
+                        assert_eq!(checked_polygonal(s, n), Some(x as $T));
+                    }
+                    assert_eq!(checked_polygonal(s, p.len() as $T), None);
+                    for (n, &x) in pyr.iter().enumerate() {
+                        let n = n as $T;
+                        assert_eq!(pyramidal(s, n) as u128, x);
+                        assert_eq!(checked_pyramidal(s, n), Some(x as $T));
+                    }
+                    assert_eq!(checked_pyramidal(s, pyr.len() as $T), None);
+                }
+
+                let n = naive(3).0.len() as $T;
+                assert_eq!(triangular(n - 1), polygonal(3, n - 1));
+                assert_eq!(checked_triangular(n - 1), Some(triangular(n - 1)));
+                assert_eq!(checked_triangular(n), None);
+                assert_eq!(checked_triangular($T::max_value()), None);
+            }
+
+            #[test]
+            fn inverse() {
+                let max = $T::max_value();
+                let mut xs: Vec<$T> = (0..1000)
+                    .filter(|&x| x <= max as i64)
+                    .map(|x| x as $T)
+                    .collect();
+                xs.extend(&[max, max - 1, max / 2, max / 2 + 1, max / 3]);
+                for s in sides() {
+                    let (p, pyr) = naive(s as u128);
+                    for &x in &xs {
+                        let x128 = x as u128;
+                        let n = floor_index(&p, x128);
+                        assert_eq!(polygonal_root(s, x) as usize, n, "s = {}, x = {}", s, x);
+                        let exact = if p[n] == x128 { Some(n as $T) } else { None };
+                        assert_eq!(is_polygonal(s, x), exact);
+
+                        let n = floor_index(&pyr, x128);
+                        assert_eq!(pyramidal_root(s, x) as usize, n, "s = {}, x = {}", s, x);
+                        let exact = if pyr[n] == x128 { Some(n as $T) } else { None };
+                        assert_eq!(is_pyramidal(s, x), exact);
+
+                        if s == 3 {
+                            assert_eq!(triangular_root(x), polygonal_root(3, x));
+                            assert_eq!(is_triangular(x), is_polygonal(3, x));
+                        }
+                    }
+                    // Every figurate number maps back to its index.
+                    for (n, &x) in p.iter().enumerate() {
+                        assert_eq!(is_polygonal(s, x as $T), Some(n as $T));
+                    }
+                    for (n, &x) in pyr.iter().enumerate() {
+                        assert_eq!(is_pyramidal(s, x as $T), Some(n as $T));
+                    }
+                }
+            }
+
+            #[test]
+            #[should_panic]
+            fn two_sides() {
+                polygonal_root(2 as $T, 10);
+            }
+        }
+    };
+}
+
+test_figurate!(i8);
+test_figurate!(i16);
+test_figurate!(i32);
+test_figurate!(u8);
+test_figurate!(u16);
+test_figurate!(u32);
+
+#[test]
+fn wide() {
+    for &x in &[
+        u64::max_value(),
+        u64::max_value() - 1,
+        1 << 63,
+        (1 << 63) - 1,
+    ] {
+        let n = triangular_root(x);
+        assert!(triangular(n) <= x);
+        assert!(n
+            .checked_add(1)
+            .and_then(|n| n.checked_mul(n + 1))
+            .map_or(true, |t| t / 2 > x));
+    }
+    for &x in &[u128::max_value(), i128::max_value() as u128, 1 << 100] {
+        let n = triangular_root(x);
+        assert!(triangular(n) <= x);
+        assert_eq!(is_triangular(triangular(n)), Some(n));
+        assert!(polygonal_root(7, x) < n);
+        let r = pyramidal_root(4, x);
+        assert!(checked_pyramidal(4, r).unwrap() <= x);
+        assert!(checked_pyramidal(4, r + 1).map_or(true, |y| y > x));
+    }
+    assert_eq!(
+        triangular_root(i128::max_value()),
+        18_446_744_073_709_551_615
+    );
+    assert_eq!(pyramidal_root(3, i64::max_value()), 3_810_777);
+}
+
+#[test]
+fn negative() {
+    assert_eq!(is_triangular(-10i32), None);
+    assert_eq!(is_triangular(i64::min_value()), None);
+}
+
+#[test]
+#[should_panic]
+fn negative_index() {
+    triangular(-1i32);
+}
+
+#[test]
+#[should_panic(expected = "figurate roots need a non-negative `x`")]
+fn negative_root() {
+    triangular_root(-1i32);
+}
@@ -0,0 +1,164 @@
+use num_integer::Logarithms;
+
+/// Floor and ceiling logarithms by repeated multiplication.
+fn naive(x: u128, base: u128) -> (u32, u32) {
+    let (mut floor, mut p) = (0, 1u128);
+    while let Some(q) = p.checked_mul(base) {
+        if q > x {
+            break;
+        }
+        floor += 1;
+        p = q;
+    }
+    let ceil = if p == x { floor } else { floor + 1 };
+    (floor, ceil)
+}
+
+macro_rules! test_logarithms {
+    ($I:ident, $U:ident) => {
+        mod $I {
+            use crate::naive;
+            use num_integer::Logarithms;
+
+            fn check(x: $I, base: $I) {
+                let (floor, ceil) = naive(x as u128, base as u128);
+                assert_eq!(Logarithms::ilog(&x, &base), floor);
+                assert_eq!(Logarithms::checked_ilog(&x, &base), Some(floor));
+                assert_eq!(x.ilog_ceil(&base), ceil);
+                assert_eq!(
+                    Logarithms::ilog(&(x as $U), &(base as $U)),
+                    floor,
+                    "unsigned"
+                );
+                assert_eq!((x as $U).ilog_ceil(&(base as $U)), ceil, "unsigned");
+                if base == 2 {
+                    assert_eq!(Logarithms::ilog2(&x), floor);
+                    assert_eq!(Logarithms::checked_ilog2(&x), Some(floor));
+                    assert_eq!(x.ilog2_ceil(), ceil);
+                    assert_eq!((x as $U).ilog2_ceil(), ceil);
+                }
+                if base == 10 {
+                    assert_eq!(Logarithms::ilog10(&x), floor);
+                    assert_eq!(Logarithms::checked_ilog10(&x), Some(floor));
+                }
+            }
+
+            fn bases() -> Vec<$I> {
+                let mut v: Vec<$I> = (2..40).collect();
+                v.extend(&[
+                    64,
+                    100,
+                    127,
+                    std::$I::MAX / 3,
+                    std::$I::MAX - 1,
+                    std::$I::MAX,
+                ]);
+                v
+            }
+
+            #[test]
+            fn powers() {
+                for base in bases() {
+                    let mut p: $I = 1;
+                    loop {
+                        check(p, base);
+                        if p > 1 {
+                            check(p - 1, base);
+                        }
+                        if p < std::$I::MAX {
+                            check(p + 1, base);
+                        }
+                        match p.checked_mul(base) {
+                            Some(q) => p = q,
+                            None => break,
+                        }
+                    }
+                    check(std::$I::MAX, base);
+                }
+            }
+
+            #[test]
+            fn non_positive() {
+                for &x in &[0, -1, -2, -10, std::$I::MIN] {
+                    assert_eq!(Logarithms::checked_ilog2(&x), None);
+                    assert_eq!(Logarithms::checked_ilog10(&x), None);
+                    assert_eq!(Logarithms::checked_ilog(&x, &3), None);
+                }
+                assert_eq!(Logarithms::checked_ilog2(&(0 as $U)), None);
+                assert_eq!(Logarithms::checked_ilog10(&(0 as $U)), None);
+                assert_eq!(Logarithms::checked_ilog(&(0 as $U), &3), None);
+            }
+
+            #[test]
+            fn bad_base() {
+                for &base in &[1, 0, -1, -2, std::$I::MIN] {
+                    assert_eq!(Logarithms::checked_ilog(&100, &base), None);
+                }
+                assert_eq!(Logarithms::checked_ilog(&(100 as $U), &0), None);
+                assert_eq!(Logarithms::checked_ilog(&(100 as $U), &1), None);
+            }
+
+            #[test]
+            #[should_panic]
+            fn ilog2_zero() {
+                Logarithms::ilog2(&(0 as $I));
+            }
+
+            #[test]
+            #[should_panic]
+            fn ilog10_negative() {
+                Logarithms::ilog10(&(-1 as $I));
+            }
+
+            #[test]
+            #[should_panic]
+            fn ilog_base_one() {
+                Logarithms::ilog(&(5 as $U), &1);
+            }
+
+            #[test]
+            #[should_panic]
+            fn ilog2_ceil_zero() {
+                (0 as $U).ilog2_ceil();
+            }
+
+            #[test]
+            #[should_panic]
+            fn ilog_ceil_negative() {
+                (-5 as $I).ilog_ceil(&3);
+            }
+        }
+    };
+}
+
+test_logarithms!(i8, u8);
+test_logarithms!(i16, u16);
+test_logarithms!(i32, u32);
+test_logarithms!(i64, u64);
+test_logarithms!(i128, u128);
+test_logarithms!(isize, usize);
+
+#[test]
+fn exhaustive_16() {
+    for x in 1..=u16::max_value() {
+        for base in 2..20 {
+            let (floor, ceil) = naive(u128::from(x), base);
+            let base = base as u16;
+            assert_eq!(Logarithms::ilog(&x, &base), floor);
+            assert_eq!(x.ilog_ceil(&base), ceil);
+        }
+    }
+}
+
+#[test]
+fn unsigned_top() {
+    // The unsigned types have one more bit than their signed cousins.
+    assert_eq!(Logarithms::ilog2(&u8::max_value()), 7);
+    assert_eq!(u8::max_value().ilog2_ceil(), 8);
+    assert_eq!(Logarithms::ilog10(&u64::max_value()), 19);
+    assert_eq!(Logarithms::ilog10(&u128::max_value()), 38);
+    assert_eq!(u128::max_value().ilog_ceil(&10), 39);
+    assert_eq!(Logarithms::ilog(&u128::max_value(), &3), 80);
+    assert_eq!((1u128 << 127).ilog_ceil(&2), 127);
+    assert_eq!(((1u128 << 127) + 1).ilog_ceil(&2), 128);
+}
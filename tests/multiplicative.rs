@@ -0,0 +1,371 @@
+use num_integer::{core_squarefree, is_squarefree, liouville, mobius, radical, Multiplicative};
+
+/// Returns the prime factors of `n` with multiplicity, by trial division.
+fn naive_factors(mut n: u64) -> Vec<u64> {
+    let mut factors = vec![];
+    let mut p = 2;
+    while p * p <= n {
+        while n % p == 0 {
+            factors.push(p);
+            n /= p;
+        }
+        p += 1;
+    }
+    if n > 1 {
+        factors.push(n);
+    }
+    factors
+}
+
+fn check(n: u64) {
+    let factors = naive_factors(n);
+    let mut distinct = factors.clone();
+    distinct.dedup();
+    let squarefree = distinct.len() == factors.len();
+
+    let sign = |k: usize| if k % 2 == 0 { 1 } else { -1 };
+    assert_eq!(mobius(n), if squarefree { sign(factors.len()) } else { 0 });
+    assert_eq!(liouville(n), sign(factors.len()));
+    assert_eq!(radical(n), distinct.iter().product());
+    assert_eq!(is_squarefree(n), squarefree);
+
+    let core: u64 = distinct
+        .iter()
+        .filter(|&&p| factors.iter().filter(|&&q| q == p).count() % 2 == 1)
+        .product();
+    assert_eq!(core_squarefree(n), core);
+    assert_eq!(n % core, 0);
+}
+
+#[test]
+fn small() {
+    for n in 1..=10_000 {
+        check(n);
+    }
+}
+
+#[test]
+fn large() {
+    for n in 10_000_000_000..10_000_000_100 {
+        check(n);
+    }
+}
+
+#[test]
+fn zero() {
+    assert_eq!(mobius(0), 0);
+    assert_eq!(liouville(0), 0);
+    assert_eq!(radical(0), 0);
+    assert!(!is_squarefree(0));
+    assert_eq!(core_squarefree(0), 0);
+}
+
+/// Checks `n` in each type that holds it against its absolute value in `u64`.
+fn check_types(n: i64) {
+    let m = n.wrapping_abs() as u64;
+    let sign = if n < 0 { -1 } else { 1 };
+    macro_rules! check_in {
+        ($T:ident, $fits:expr) => {
+            if $fits {
+                let x = n as $T;
+                assert_eq!(x.mobius(), mobius(m), "μ({})", n);
+                assert_eq!(x.liouville(), liouville(m), "λ({})", n);
+                assert_eq!(x.radical() as u64, radical(m), "rad({})", n);
+                assert_eq!(x.is_squarefree(), is_squarefree(m), "{}", n);
+                let core = x.core_squarefree() as i64;
+                assert_eq!(core, sign * core_squarefree(m) as i64, "core({})", n);
+            }
+        };
+    }
+    check_in!(i8, n >= -128 && n <= 127);
+    check_in!(i16, n >= -32_768 && n <= 32_767);
+    check_in!(i32, n >= -(1 << 31) && n < 1 << 31);
+    check_in!(i64, true);
+    check_in!(i128, true);
+    check_in!(isize, n as isize as i64 == n);
+    check_in!(u8, n >= 0 && n <= 255);
+    check_in!(u16, n >= 0 && n <= 65_535);
+    check_in!(u32, n >= 0 && n < 1 << 32);
+    check_in!(u64, n >= 0);
+    check_in!(u128, n >= 0);
+    check_in!(usize, n >= 0 && n as usize as i64 == n);
+}
+
+#[test]
+fn types() {
+    for n in -1000..=1000 {
+        check_types(n);
+    }
+    for k in 0..63 {
+        check_types(1 << k);
+        check_types(-(1 << k));
+        check_types((1 << k) - 1);
+        check_types(1 - (1 << k));
+    }
+    check_types(i64::max_value());
+    check_types(i64::min_value());
+}
+
+#[test]
+fn wide() {
+    // (n, μ, λ, rad, core), checked with arbitrary precision.
+    let cases: [(u128, i8, i8, u128, u128); 14] = [
+        // 2¹²⁷ - 1 and 2¹²⁸ - 159 are prime.
+        ((1 << 127) - 1, -1, -1, (1 << 127) - 1, (1 << 127) - 1),
+        (
+            u128::max_value() - 158,
+            -1,
+            -1,
+            u128::max_value() - 158,
+            u128::max_value() - 158,
+        ),
+        (
+            u128::max_value(),
+            -1,
+            -1,
+            u128::max_value(),
+            u128::max_value(),
+        ),
+        // 3·(2⁶¹ - 1)²
+        (
+            15_950_735_949_418_990_461_010_626_668_081_971_203,
+            0,
+            -1,
+            6_917_529_027_641_081_853,
+            3,
+        ),
+        // (2³¹ - 1)⁴
+        (
+            21_267_647_892_944_572_736_998_860_269_687_930_881,
+            0,
+            1,
+            2_147_483_647,
+            1,
+        ),
+        // 9_223_372_036_854_775_837²
+        (
+            85_070_591_730_234_616_400_799_229_995_519_050_569,
+            0,
+            1,
+            9_223_372_036_854_775_837,
+            1,
+        ),
+        // 1_000_000_007·(2⁸⁹ - 1)
+        (
+            618_970_023_975_480_274_948_393_073_146_934_777,
+            1,
+            1,
+            618_970_023_975_480_274_948_393_073_146_934_777,
+            618_970_023_975_480_274_948_393_073_146_934_777,
+        ),
+        // The primes after 2³⁰, 2³¹ and 2⁶⁰.
+        (
+            2_658_456_012_614_812_527_798_308_352_292_815_937,
+            -1,
+            -1,
+            2_658_456_012_614_812_527_798_308_352_292_815_937,
+            2_658_456_012_614_812_527_798_308_352_292_815_937,
+        ),
+        // A strong pseudoprime to base 2, 4_294_969_489·8_589_938_977.
+        (
+            36_893_525_818_586_872_753,
+            1,
+            1,
+            36_893_525_818_586_872_753,
+            36_893_525_818_586_872_753,
+        ),
+        (10u128.pow(38), 0, 1, 10, 1),
+        (3 << 64, 0, -1, 6, 3),
+        ((1 << 64) + 1, 1, 1, (1 << 64) + 1, (1 << 64) + 1),
+        ((1 << 100) + 1, 1, 1, (1 << 100) + 1, (1 << 100) + 1),
+        // 3·5·7 times the prime after 2¹⁰⁰.
+        (
+            133_103_313_023_964_087_157_153_836_593_565,
+            1,
+            1,
+            133_103_313_023_964_087_157_153_836_593_565,
+            133_103_313_023_964_087_157_153_836_593_565,
+        ),
+    ];
+    for &(n, mu, lambda, rad, core) in &cases {
+        assert_eq!(mobius(n), mu, "μ({})", n);
+        assert_eq!(liouville(n), lambda, "λ({})", n);
+        assert_eq!(radical(n), rad, "rad({})", n);
+        assert_eq!(is_squarefree(n), mu != 0, "{}", n);
+        assert_eq!(core_squarefree(n), core, "core({})", n);
+
+        if n <= i128::max_value() as u128 {
+            let m = -(n as i128);
+            assert_eq!(mobius(m), mu);
+            assert_eq!(liouville(m), lambda);
+            assert_eq!(radical(m), rad as i128);
+            assert_eq!(is_squarefree(m), mu != 0);
+            assert_eq!(core_squarefree(m), -(core as i128));
+        }
+    }
+    assert_eq!(i128::min_value().radical(), 2);
+    assert_eq!(i128::min_value().core_squarefree(), -2);
+    assert_eq!(i128::max_value().mobius(), -1);
+}
+
+#[test]
+fn negative() {
+    assert_eq!(mobius(-6i32), 1);
+    assert_eq!(liouville(-8i32), -1);
+    assert_eq!(radical(-72i32), 6);
+    assert!(is_squarefree(-1i32));
+    assert_eq!(core_squarefree(-72i32), -2);
+    assert_eq!(core_squarefree(-1i8), -1);
+    assert_eq!(i64::min_value().mobius(), 0);
+    assert_eq!(i64::min_value().liouville(), -1);
+    assert_eq!(i64::min_value().radical(), 2);
+    assert_eq!(i64::min_value().core_squarefree(), -2);
+}
+
+#[cfg(feature = "alloc")]
+mod range {
+    use num_integer::{core_squarefree, is_squarefree, liouville, mobius, radical};
+    use num_integer::{
+        core_squarefree_range, liouville_range, mobius_range, radical_range, squarefree_range,
+    };
+
+    fn check_range(start: u64, len: usize) {
+        let mut mu = vec![9; len];
+        let mut lambda = vec![9; len];
+        let mut rad = vec![9; len];
+        let mut sf = vec![false; len];
+        let mut core = vec![9; len];
+        mobius_range(start, &mut mu);
+        liouville_range(start, &mut lambda);
+        radical_range(start, &mut rad);
+        squarefree_range(start, &mut sf);
+        core_squarefree_range(start, &mut core);
+
+        for i in 0..len {
+            let n = start + i as u64;
+            assert_eq!(mu[i], mobius(n), "μ({})", n);
+            assert_eq!(lambda[i], liouville(n), "λ({})", n);
+            assert_eq!(rad[i], radical(n), "rad({})", n);
+            assert_eq!(sf[i], is_squarefree(n), "is_squarefree({})", n);
+            assert_eq!(core[i], core_squarefree(n), "core({})", n);
+        }
+    }
+
+    #[test]
+    fn from_zero() {
+        check_range(0, 10_000);
+    }
+
+    #[test]
+    fn offset() {
+        check_range(12_345, 1_000);
+        check_range(1_000_000, 5_000);
+        check_range(1_000_000_000_000, 1_000);
+        check_range(std::u64::MAX - 999, 1_000);
+    }
+
+    #[test]
+    fn empty() {
+        check_range(0, 0);
+        check_range(std::u64::MAX, 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn overflow() {
+        mobius_range(std::u64::MAX, &mut [0; 2]);
+    }
+
+    fn check_signed_range(start: i64, len: usize) {
+        let mut mu = vec![9; len];
+        let mut lambda = vec![9; len];
+        let mut rad = vec![9; len];
+        let mut sf = vec![false; len];
+        let mut core = vec![9; len];
+        mobius_range(start, &mut mu);
+        liouville_range(start, &mut lambda);
+        radical_range(start, &mut rad);
+        squarefree_range(start, &mut sf);
+        core_squarefree_range(start, &mut core);
+
+        for i in 0..len {
+            let n = start + i as i64;
+            assert_eq!(mu[i], mobius(n), "μ({})", n);
+            assert_eq!(lambda[i], liouville(n), "λ({})", n);
+            assert_eq!(rad[i], radical(n), "rad({})", n);
+            assert_eq!(sf[i], is_squarefree(n), "is_squarefree({})", n);
+            assert_eq!(core[i], core_squarefree(n), "core({})", n);
+        }
+    }
+
+    #[test]
+    fn negative() {
+        check_signed_range(-10_000, 20_001);
+        check_signed_range(-10_000, 5_000);
+        check_signed_range(-3, 2);
+        check_signed_range(-1, 1);
+        check_signed_range(std::i64::MIN, 1_000);
+        check_signed_range(std::i64::MAX - 999, 1_000);
+    }
+
+    #[test]
+    fn small_types() {
+        let mut mu = [9; 256];
+        let mut core = [9; 256];
+        mobius_range(std::i8::MIN, &mut mu);
+        core_squarefree_range(std::i8::MIN, &mut core);
+        for (i, n) in (-128..=127i8).enumerate() {
+            assert_eq!(mu[i], mobius(n));
+            assert_eq!(core[i], core_squarefree(n));
+        }
+
+        let mut rad = [9; 256];
+        radical_range(0u8, &mut rad);
+        for (i, n) in (0..=255u8).enumerate() {
+            assert_eq!(rad[i], radical(n));
+        }
+    }
+
+    #[test]
+    fn wide() {
+        // Ranges whose magnitudes don't all fit in a `u64` are factored one
+        // value at a time.
+        let mut mu = [9; 100];
+        let mut core = [9; 100];
+        let start = u128::from(std::u64::MAX) - 49;
+        mobius_range(start, &mut mu);
+        core_squarefree_range(start, &mut core);
+        for i in 0..100 {
+            let n = start + i as u128;
+            assert_eq!(mu[i], mobius(n), "μ({})", n);
+            assert_eq!(core[i], core_squarefree(n), "core({})", n);
+        }
+
+        let mut rad = [9; 4];
+        let mut sf = [false; 4];
+        let start = std::i128::MIN;
+        radical_range(start, &mut rad);
+        squarefree_range(start, &mut sf);
+        for i in 0..4 {
+            let n = start + i as i128;
+            assert_eq!(rad[i], radical(n), "rad({})", n);
+            assert_eq!(sf[i], is_squarefree(n), "is_squarefree({})", n);
+        }
+
+        // Around zero, they're still sifted.
+        let mut lambda = [9; 2001];
+        let mut core = [9; 2001];
+        liouville_range(-1000i128, &mut lambda);
+        core_squarefree_range(-1000i128, &mut core);
+        for (i, n) in (-1000..=1000i128).enumerate() {
+            assert_eq!(lambda[i], liouville(n), "λ({})", n);
+            assert_eq!(core[i], core_squarefree(n), "core({})", n);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn overflow_small() {
+        mobius_range(250u8, &mut [0; 10]);
+    }
+}
@@ -0,0 +1,176 @@
+use num_integer::{factorize, is_prime};
+#[cfg(feature = "alloc")]
+use num_integer::{nth_prime, prime_pi};
+
+/// Returns the primes up to and including `n`, by a plain sieve of Eratosthenes.
+fn sieve(n: usize) -> Vec<u64> {
+    let mut composite = vec![false; n + 1];
+    let mut primes = vec![];
+    for i in 2..=n {
+        if !composite[i] {
+            primes.push(i as u64);
+            for j in (i * i..=n).step_by(i) {
+                composite[j] = true;
+            }
+        }
+    }
+    primes
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn prime_pi_small() {
+    let primes = sieve(10_000);
+    let mut count = 0;
+    for x in 0..=10_000 {
+        if primes.get(count) == Some(&x) {
+            count += 1;
+        }
+        assert_eq!(prime_pi(x), count as u64, "π({})", x);
+    }
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn prime_pi_powers_of_ten() {
+    let expected = [
+        0,
+        4,
+        25,
+        168,
+        1_229,
+        9_592,
+        78_498,
+        664_579,
+        5_761_455,
+        50_847_534,
+        455_052_511,
+    ];
+    for (k, &pi) in expected.iter().enumerate() {
+        assert_eq!(prime_pi(10u64.pow(k as u32)), pi);
+    }
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn nth_prime_small() {
+    for (i, &p) in sieve(10_000).iter().enumerate() {
+        assert_eq!(nth_prime(i as u64 + 1), p);
+    }
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn nth_prime_large() {
+    assert_eq!(nth_prime(1_000_000), 15_485_863);
+    assert_eq!(nth_prime(10_000_000), 179_424_673);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+#[should_panic]
+fn nth_prime_zero() {
+    nth_prime(0);
+}
+
+#[test]
+fn is_prime_small() {
+    let primes = sieve(100_000);
+    let mut iter = primes.iter().peekable();
+    for n in 0..=100_000 {
+        let expected = iter.peek() == Some(&&n);
+        if expected {
+            iter.next();
+        }
+        assert_eq!(is_prime(n), expected, "is_prime({})", n);
+    }
+}
+
+#[test]
+fn is_prime_pseudoprimes() {
+    // Carmichael numbers and strong pseudoprimes to several prime bases
+    let composites = [
+        561,
+        1_105,
+        1_729,
+        2_047,
+        1_373_653,
+        25_326_001,
+        3_215_031_751,
+        2_152_302_898_747,
+        3_474_749_660_383,
+        341_550_071_728_321,
+        3_825_123_056_546_413_051,
+        4_294_967_297, // F₅ = 641 × 6700417
+        18_446_744_073_709_551_615,
+    ];
+    for &n in &composites {
+        assert!(!is_prime(n), "{} is composite", n);
+    }
+
+    let primes = [
+        4_294_967_291,
+        4_294_967_311,
+        1_000_000_000_000_000_003,
+        18_446_744_073_709_551_557,
+    ];
+    for &p in &primes {
+        assert!(is_prime(p), "{} is prime", p);
+    }
+}
+
+fn check_factorization(n: u64) {
+    let f = factorize(n);
+    assert_eq!(f.value(), n);
+    let mut last = 1;
+    for &(p, e) in &f {
+        assert!(p > last, "{:?} is not sorted", f);
+        assert!(e > 0);
+        assert!(is_prime(p), "{} is not prime in {:?}", p, f);
+        last = p;
+    }
+}
+
+#[test]
+fn factorize_small() {
+    assert!(factorize(1).is_empty());
+    for n in 1..=100_000 {
+        check_factorization(n);
+    }
+}
+
+#[test]
+fn factorize_large() {
+    let semiprimes = [
+        (4_294_967_291, 4_294_967_279),
+        (1_000_000_007, 998_244_353),
+        (65_521, 281_474_976_710_597),
+        (3, 6_148_914_691_236_517_199),
+    ];
+    for &(p, q) in &semiprimes {
+        let f = factorize(p * q);
+        let (p, q) = if p < q { (p, q) } else { (q, p) };
+        assert_eq!(f.as_slice(), &[(p, 1), (q, 1)]);
+    }
+
+    assert_eq!(factorize(1 << 63).as_slice(), &[(2, 63)]);
+    assert_eq!(factorize(3u64.pow(40)).as_slice(), &[(3, 40)]);
+    assert_eq!(
+        factorize(4_294_967_291 * 4_294_967_291).as_slice(),
+        &[(4_294_967_291, 2)]
+    );
+
+    let mut n = std::u64::MAX;
+    for _ in 0..1_000 {
+        check_factorization(n);
+        n -= 1;
+    }
+    check_factorization(614_889_782_588_491_410); // product of the first 15 primes
+    assert_eq!(factorize(614_889_782_588_491_410).len(), 15);
+}
+
+#[test]
+#[should_panic]
+fn factorize_zero() {
+    factorize(0);
+}
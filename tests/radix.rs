@@ -0,0 +1,152 @@
+use num_integer::{format_radix, RadixDisplay};
+
+macro_rules! test_radix {
+    ($T:ident) => {
+        mod $T {
+            use num_integer::{format_radix, RadixDisplay};
+            use num_traits::Num;
+
+            fn values() -> Vec<$T> {
+                let (min, max) = ($T::min_value(), $T::max_value());
+                let mut v: Vec<$T> = (0..100).map(|x| x as $T).collect();
+                v.extend(&[min, min + 1, max, max - 1, max / 3, min / 3]);
+                v
+            }
+
+            #[test]
+            fn std_radices() {
+                let mut buf = [0u8; 129];
+                for x in values() {
+                    assert_eq!(format_radix(x, 10, &mut buf), format!("{}", x));
+                    let y = RadixDisplay::new(x, 10);
+                    assert_eq!(format!("{:08}", y), format!("{:08}", x));
+                    assert_eq!(format!("{:+08}", y), format!("{:+08}", x));
+                    assert_eq!(format!("{:.2}", y), format!("{:.2}", x));
+                    assert_eq!(format!("{:^+9.1}", y), format!("{:^+9.1}", x));
+                    if !x.to_string().starts_with('-') {
+                        assert_eq!(format_radix(x, 2, &mut buf), format!("{:b}", x));
+                        assert_eq!(format_radix(x, 8, &mut buf), format!("{:o}", x));
+                        assert_eq!(format_radix(x, 16, &mut buf), format!("{:x}", x));
+                        let upper = RadixDisplay::new(x, 16).uppercase(true);
+                        assert_eq!(upper.to_string(), format!("{:X}", x));
+                    }
+                }
+            }
+
+            #[test]
+            fn round_trip() {
+                let mut buf = [0u8; 129];
+                for x in values() {
+                    for radix in 2..=36 {
+                        let s = format_radix(x, radix, &mut buf);
+                        assert_eq!(<$T as Num>::from_str_radix(s, radix), Ok(x), "{}", s);
+                        let upper = RadixDisplay::new(x, radix).uppercase(true).to_string();
+                        assert_eq!(upper, s.to_uppercase());
+                    }
+                }
+            }
+
+            #[test]
+            fn widest() {
+                // Binary with every option needs the whole Display buffer.
+                let x = RadixDisplay::new($T::min_value(), 2)
+                    .plus_sign(true)
+                    .min_digits(128)
+                    .group(1, '_');
+                let s = x.to_string();
+                assert_eq!(s.len(), 256);
+                let min = $T::min_value().to_string();
+                assert_eq!(&s[..1], if min.starts_with('-') { "-" } else { "+" });
+                assert_eq!(s.replace('_', "").len(), 129);
+            }
+        }
+    };
+}
+
+test_radix!(i8);
+test_radix!(i16);
+test_radix!(i32);
+test_radix!(i64);
+test_radix!(i128);
+test_radix!(isize);
+test_radix!(u8);
+test_radix!(u16);
+test_radix!(u32);
+test_radix!(u64);
+test_radix!(u128);
+test_radix!(usize);
+
+#[test]
+fn options() {
+    let x = RadixDisplay::new(0u8, 2);
+    assert_eq!(x.to_string(), "0");
+    assert_eq!(x.min_digits(0).to_string(), "0");
+    assert_eq!(x.plus_sign(true).to_string(), "+0");
+    assert_eq!(x.min_digits(6).group(4, ' ').to_string(), "00 0000");
+    assert_eq!(x.min_digits(8).group(4, ' ').to_string(), "0000 0000");
+
+    let x = RadixDisplay::new(-1_000_000i64, 10).group(3, ',');
+    assert_eq!(x.to_string(), "-1,000,000");
+    assert_eq!(x.plus_sign(true).to_string(), "-1,000,000");
+    assert_eq!(format!("{:*^14}", x), "**-1,000,000**");
+    assert_eq!(format!("{:<12}|", x), "-1,000,000  |");
+    assert_eq!(format!("{:012}", x), "-001,000,000");
+    assert_eq!(format!("{:.2}", x), "-1,000,000");
+
+    let x = RadixDisplay::new(255u32, 10);
+    assert_eq!(format!("{:.2}", x), "255");
+    assert_eq!(format!("{:6.1}|", x), "   255|");
+    assert_eq!(format!("{:+}", x), "+255");
+    assert_eq!(format!("{:+}", x.plus_sign(true)), "+255");
+    assert_eq!(format!("{:06}", x.plus_sign(true)), "+00255");
+    assert_eq!(format!("{:+06}", x.plus_sign(true)), "+00255");
+
+    let x = RadixDisplay::new(-255i32, 16);
+    assert_eq!(format!("{:08}", x), "-00000ff");
+    assert_eq!(format!("{:<08}", x), "-00000ff");
+    assert_eq!(format!("{:8}", x), "     -ff");
+    assert_eq!(format!("{:08}", x), format!("-{:07x}", 255));
+
+    let x = RadixDisplay::new(u128::max_value(), 36);
+    assert_eq!(x.to_string(), "f5lxx1zz5pnorynqglhzmsp33");
+    assert_eq!(
+        u128::from_str_radix(&x.to_string(), 36),
+        Ok(u128::max_value())
+    );
+}
+
+#[test]
+fn buffers() {
+    let mut buf = [b'#'; 8];
+    assert_eq!(format_radix(-255i16, 16, &mut buf), "-ff");
+    assert_eq!(&buf[..5], b"#####");
+
+    let x = RadixDisplay::new(-255i16, 16);
+    assert_eq!(x.format_into(&mut buf[..3]), Some("-ff"));
+    assert_eq!(x.format_into(&mut buf[..2]), None);
+    assert_eq!(x.format_into(&mut []), None);
+}
+
+#[test]
+#[should_panic]
+fn small_buffer() {
+    format_radix(1000u32, 10, &mut [0u8; 3]);
+}
+
+#[test]
+#[should_panic]
+fn radix_one() {
+    RadixDisplay::new(10u32, 1);
+}
+
+#[test]
+#[should_panic]
+fn radix_37() {
+    RadixDisplay::new(10u32, 37);
+}
+
+#[test]
+#[should_panic]
+fn too_much_padding() {
+    RadixDisplay::new(10u32, 10).min_digits(129);
+}
@@ -20,10 +20,21 @@ where
     for i in v {
         let rt = i.nth_root(n);
         // println!("nth_root({:?}, {}) = {:?}", i, n, rt);
+        assert_eq!(i.nth_root_rem(n), (rt, *i - rt.pow(n)));
+        assert_eq!(i.checked_nth_root(n), Some(rt));
+        let exact = if rt.pow(n) == *i { Some(rt) } else { None };
+        assert_eq!(i.exact_nth_root(n), exact);
         if n == 2 {
             assert_eq!(rt, i.sqrt());
+            assert_eq!(i.sqrt_rem(), (rt, *i - rt * rt));
+            assert_eq!(i.checked_sqrt(), Some(rt));
+            let exact = if rt * rt == *i { Some(rt) } else { None };
+            assert_eq!(i.exact_sqrt(), exact);
+            assert_eq!(i.is_perfect_square(), exact.is_some());
         } else if n == 3 {
             assert_eq!(rt, i.cbrt());
+            assert_eq!(i.checked_cbrt(), Some(rt));
+            assert_eq!(i.exact_cbrt(), exact);
         }
         if *i >= T::zero() {
             let rt1 = rt + T::one();
@@ -181,6 +192,20 @@ macro_rules! test_roots {
                 (-123 as $I).sqrt();
             }
 
+            #[test]
+            fn checked_imaginary() {
+                assert_eq!((123 as $I).checked_nth_root(0), None);
+                assert_eq!((-123 as $I).checked_nth_root(0), None);
+                assert_eq!((-123 as $I).checked_sqrt(), None);
+                assert_eq!($I::min_value().checked_sqrt(), None);
+                assert_eq!((-123 as $I).exact_nth_root(0), None);
+                for n in (2..64).step_by(2) {
+                    assert_eq!((-1 as $I).checked_nth_root(n), None);
+                    assert_eq!($I::min_value().checked_nth_root(n), None);
+                    assert_eq!((-1 as $I).exact_nth_root(n), None);
+                }
+            }
+
             #[test]
             fn cbrt() {
                 check(&pos::<$I>(), 3);
@@ -217,6 +242,19 @@ macro_rules! test_roots {
                 assert_eq!($I::min_value().nth_root(bits), -2);
                 assert_eq!(($I::min_value() + 1).nth_root(bits), -1);
             }
+
+            #[test]
+            fn first_root_min() {
+                let min = $I::min_value();
+                assert_eq!(min.checked_nth_root(1), Some(min));
+                assert_eq!(min.nth_root(1), min);
+                assert_eq!(min.nth_root_rem(1), (min, 0));
+                assert_eq!(min.checked_cbrt(), Some(min.cbrt()));
+                assert_eq!(min.exact_nth_root(1), Some(min));
+                assert_eq!((min + 1).exact_nth_root(1), Some(min + 1));
+                let bits = 8 * mem::size_of::<$I>() as u32 - 1;
+                assert_eq!(min.exact_nth_root(bits), Some(-2));
+            }
         }
 
         mod $U {
@@ -231,6 +269,13 @@ macro_rules! test_roots {
                 (123 as $U).nth_root(0);
             }
 
+            #[test]
+            fn checked_zeroth_root() {
+                assert_eq!((123 as $U).checked_nth_root(0), None);
+                assert_eq!((0 as $U).checked_nth_root(0), None);
+                assert_eq!((1 as $U).exact_nth_root(0), None);
+            }
+
             #[test]
             fn sqrt() {
                 check(&pos::<$U>(), 2);
@@ -266,3 +311,562 @@ test_roots!(i32, u32);
 test_roots!(i64, u64);
 test_roots!(i128, u128);
 test_roots!(isize, usize);
+
+#[test]
+fn sqrt_table() {
+    // Every leading byte, at every scale, and the squares around them.
+    let mut v: Vec<u64> = vec![];
+    for shift in 0..57 {
+        for top in 128..256u64 {
+            let x = top << shift;
+            let r = x.sqrt();
+            v.extend(&[x - 1, x, x + 1, r * r - 1, r * r, (r + 1) * (r + 1) - 1]);
+        }
+    }
+    v.push(u64::max_value());
+    check(&v, 2);
+}
+
+mod perfect_power {
+    use num_integer::is_perfect_power;
+    use num_traits::checked_pow;
+
+    /// Find the largest exponent by brute force, checking every degree.
+    fn naive<T: super::TestInteger>(x: T) -> Option<(T, u32)> {
+        let bits = 8 * std::mem::size_of::<T>() as u32;
+        for k in (2..bits).rev() {
+            if x < T::zero() && k % 2 == 0 {
+                continue;
+            }
+            let r = x.nth_root(k);
+            let trivial = r <= T::one() && (r >= T::zero() || (r + T::one()).is_zero());
+            if !trivial && checked_pow(r, k as usize) == Some(x) {
+                return Some((r, k));
+            }
+        }
+        None
+    }
+
+    #[test]
+    fn exhaustive_8() {
+        for x in i8::min_value()..=i8::max_value() {
+            assert_eq!(is_perfect_power(x), naive(x), "{}", x);
+        }
+        for x in u8::min_value()..=u8::max_value() {
+            assert_eq!(is_perfect_power(x), naive(x), "{}", x);
+        }
+    }
+
+    #[test]
+    fn exhaustive_16() {
+        for x in i16::min_value()..=i16::max_value() {
+            assert_eq!(is_perfect_power(x), naive(x), "{}", x);
+        }
+        for x in u16::min_value()..=u16::max_value() {
+            assert_eq!(is_perfect_power(x), naive(x), "{}", x);
+        }
+    }
+
+    #[test]
+    fn powers() {
+        assert_eq!(is_perfect_power(1u64 << 63), Some((2, 63)));
+        assert_eq!(is_perfect_power(3u64.pow(40)), Some((3, 40)));
+        assert_eq!(is_perfect_power(3u64.pow(40) + 1), None);
+        assert_eq!(is_perfect_power(6u64.pow(24)), Some((6, 24)));
+        assert_eq!(is_perfect_power(i64::min_value()), Some((-2, 63)));
+        assert_eq!(is_perfect_power(-(3i64.pow(39))), Some((-3, 39)));
+        assert_eq!(is_perfect_power(-(3i64.pow(38))), Some((-9, 19)));
+        assert_eq!(is_perfect_power(1u128 << 127), Some((2, 127)));
+        assert_eq!(is_perfect_power(u128::max_value()), None);
+        assert_eq!(
+            is_perfect_power(4_294_967_291u128.pow(4)),
+            Some((4_294_967_291, 4))
+        );
+        assert_eq!(is_perfect_power(4_294_967_291u64 * 4_294_967_279), None);
+    }
+}
+
+mod perfect_square {
+    use num_integer::Roots;
+
+    #[test]
+    fn exhaustive_16() {
+        let mut squares = vec![false; 1 << 16];
+        for r in 0..=255 {
+            squares[r * r] = true;
+        }
+        for x in 0..=u16::max_value() {
+            let square = squares[x as usize];
+            assert_eq!(x.is_perfect_square(), square, "{}", x);
+            assert_eq!((x as u32).is_perfect_square(), square, "{}", x);
+            if x <= i16::max_value() as u16 {
+                assert_eq!((x as i16).is_perfect_square(), square, "{}", x);
+            }
+            if x <= u8::max_value() as u16 {
+                assert_eq!((x as u8).is_perfect_square(), square, "{}", x);
+            }
+        }
+        for x in i16::min_value()..0 {
+            assert!(!x.is_perfect_square());
+            assert_eq!(x.exact_sqrt(), None);
+        }
+    }
+
+    #[test]
+    fn large() {
+        for &r in &[u32::max_value(), 3_037_000_499, 123_456_789, 65_536] {
+            let r = u64::from(r);
+            assert_eq!((r * r).exact_sqrt(), Some(r));
+            assert_eq!((r * r - 1).exact_sqrt(), None);
+            assert_eq!((r * r + 1).exact_sqrt(), None);
+        }
+
+        let r = u128::from(u64::max_value());
+        assert_eq!((r * r).exact_sqrt(), Some(r));
+        assert_eq!((r * r - 1).exact_sqrt(), None);
+        assert_eq!(((r - 1) * (r - 1)).exact_sqrt(), Some(r - 1));
+        assert_eq!(u128::max_value().exact_sqrt(), None);
+
+        assert_eq!(i64::max_value().exact_sqrt(), None);
+        assert_eq!(
+            (3_037_000_499i64 * 3_037_000_499).exact_sqrt(),
+            Some(3_037_000_499)
+        );
+        assert_eq!(i128::min_value().exact_sqrt(), None);
+    }
+}
+
+mod rounded {
+    use num_integer::{Roots, Rounding};
+    use num_traits::checked_pow;
+
+    /// Returns the floor, ceiling, and nearest `n`th roots of `a ≥ 0` by brute force.
+    fn naive(a: u128, n: u32) -> (u128, u128, u128) {
+        let fits = |k: u128| checked_pow(k, n as usize).map_or(false, |p| p <= a);
+        let floor = (0..=a).take_while(|&k| fits(k)).last().unwrap();
+        let ceil = if floor.pow(n) == a { floor } else { floor + 1 };
+        let up = match (
+            checked_pow(2, n as usize),
+            checked_pow(2 * floor + 1, n as usize),
+        ) {
+            (Some(p), Some(m)) => p * a > m,
+            _ => false,
+        };
+        (floor, ceil, floor + up as u128)
+    }
+
+    #[test]
+    fn exhaustive_8() {
+        for n in 1..40 {
+            for x in 0..=u8::max_value() {
+                let (floor, ceil, nearest) = naive(u128::from(x), n);
+                let (floor, ceil, nearest) = (floor as u8, ceil as u8, nearest as u8);
+                assert_eq!(x.nth_root_rounded(n, Rounding::Floor), floor);
+                assert_eq!(x.nth_root_rounded(n, Rounding::TowardZero), floor);
+                assert_eq!(x.nth_root_rounded(n, Rounding::Ceiling), ceil);
+                assert_eq!(x.nth_root_rounded(n, Rounding::AwayFromZero), ceil);
+                assert_eq!(x.nth_root_rounded(n, Rounding::Nearest), nearest);
+                assert_eq!(x.nth_root_ceil(n), ceil);
+                if n == 2 {
+                    assert_eq!(x.sqrt_ceil(), ceil);
+                } else if n == 3 {
+                    assert_eq!(x.cbrt_ceil(), ceil);
+                }
+            }
+
+            for x in i8::min_value()..=i8::max_value() {
+                if x < 0 && n % 2 == 0 {
+                    continue;
+                }
+                let (floor, ceil, nearest) = naive(i16::from(x).abs() as u128, n);
+                let (floor, ceil, nearest) = (floor as i8, ceil as i8, nearest as i8);
+                let (floor, ceil, zero, away, nearest) = if x >= 0 {
+                    (floor, ceil, floor, ceil, nearest)
+                } else {
+                    let neg = i8::wrapping_neg;
+                    (neg(ceil), neg(floor), neg(floor), neg(ceil), neg(nearest))
+                };
+                assert_eq!(x.nth_root_rounded(n, Rounding::Floor), floor);
+                assert_eq!(x.nth_root_rounded(n, Rounding::Ceiling), ceil);
+                assert_eq!(x.nth_root_rounded(n, Rounding::TowardZero), zero);
+                assert_eq!(x.nth_root_rounded(n, Rounding::AwayFromZero), away);
+                assert_eq!(x.nth_root_rounded(n, Rounding::Nearest), nearest);
+                assert_eq!(x.nth_root_ceil(n), ceil);
+                if n == 2 {
+                    assert_eq!(x.sqrt_ceil(), ceil);
+                } else if n == 3 {
+                    assert_eq!(x.cbrt_ceil(), ceil);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn nearest_boundaries() {
+        // The smallest `x` rounding up to `r + 1` is `⌈(r + ½)ⁿ⌉ = ⌈(2r + 1)ⁿ / 2ⁿ⌉`.
+        for n in 2..7 {
+            for r in 1000..1010u64 {
+                let m = u128::from(2 * r + 1).pow(n);
+                let t = ((m + (1 << n) - 1) >> n) as u64;
+                assert_eq!((t - 1).nth_root_rounded(n, Rounding::Nearest), r);
+                assert_eq!(t.nth_root_rounded(n, Rounding::Nearest), r + 1);
+                if n % 2 == 1 {
+                    let (t, r) = (-(t as i64), -(r as i64));
+                    assert_eq!((t + 1).nth_root_rounded(n, Rounding::Nearest), r);
+                    assert_eq!(t.nth_root_rounded(n, Rounding::Nearest), r - 1);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn large() {
+        let max = u64::max_value();
+        assert_eq!(max.sqrt_ceil(), 1 << 32);
+        assert_eq!(max.nth_root_rounded(2, Rounding::Nearest), 1 << 32);
+        assert_eq!(max.cbrt_ceil(), 2_642_246);
+        assert_eq!(max.nth_root_rounded(3, Rounding::Nearest), 2_642_246);
+        assert_eq!(max.nth_root_rounded(5, Rounding::Nearest), 7132);
+        assert_eq!(max.nth_root_rounded(40, Rounding::Nearest), 3);
+        assert_eq!(max.nth_root_rounded(109, Rounding::Nearest), 2);
+        assert_eq!(max.nth_root_rounded(110, Rounding::Nearest), 1);
+        assert_eq!((1u64 << 63).nth_root_rounded(100, Rounding::Nearest), 2);
+        assert_eq!(max.nth_root_ceil(1000), 2);
+        assert_eq!(max.nth_root_rounded(1000, Rounding::Nearest), 1);
+
+        let max = u128::max_value();
+        assert_eq!(max.sqrt_ceil(), 1 << 64);
+        assert_eq!(
+            max.nth_root_rounded(3, Rounding::Nearest),
+            6_981_463_658_332
+        );
+        assert_eq!(max.nth_root_rounded(7, Rounding::Nearest), 319_557);
+        assert_eq!(max.nth_root_rounded(218, Rounding::Nearest), 2);
+        assert_eq!(max.nth_root_rounded(219, Rounding::Nearest), 1);
+        assert_eq!(max.nth_root_rounded(u32::max_value(), Rounding::Nearest), 1);
+        assert_eq!(0u128.nth_root_rounded(300, Rounding::Nearest), 0);
+
+        assert_eq!(i64::min_value().cbrt_ceil(), -2_097_152);
+        assert_eq!(i128::min_value().nth_root_rounded(127, Rounding::Floor), -2);
+        assert_eq!(i128::min_value().nth_root_rounded(129, Rounding::Floor), -2);
+        assert_eq!(
+            i128::min_value().nth_root_rounded(129, Rounding::Ceiling),
+            -1
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn sqrt_ceil_neg() {
+        (-1i32).sqrt_ceil();
+    }
+
+    #[test]
+    #[should_panic]
+    fn zeroth_root() {
+        (-8i32).nth_root_rounded(0, Rounding::Nearest);
+    }
+}
+
+mod generic {
+    use crate::{neg, pos};
+    use num_integer::{generic_cbrt, generic_nth_root, generic_sqrt, Roots};
+
+    #[test]
+    fn matches_roots() {
+        // Widen so the Newton iteration has headroom for its powers.
+        let pos: Vec<u128> = pos::<u64>().into_iter().map(u128::from).collect();
+        for &x in &pos {
+            assert_eq!(generic_sqrt(x), x.sqrt(), "√{}", x);
+            assert_eq!(generic_cbrt(x), x.cbrt(), "∛{}", x);
+            for n in 1..64 {
+                assert_eq!(generic_nth_root(x, n), x.nth_root(n), "{}√{}", n, x);
+            }
+        }
+
+        let neg: Vec<i128> = neg::<i64>().into_iter().map(i128::from).collect();
+        for &x in &neg {
+            assert_eq!(generic_cbrt(x), x.cbrt(), "∛{}", x);
+            for n in (1..64).step_by(2) {
+                assert_eq!(generic_nth_root(x, n), x.nth_root(n), "{}√{}", n, x);
+            }
+        }
+    }
+
+    #[test]
+    fn extremes() {
+        // The starting guess's powers overflow here, so the Newton step
+        // mustn't compute them.
+        macro_rules! check {
+            ($($T:ty)*) => {$({
+                let bits = 8 * core::mem::size_of::<$T>() as u32;
+                let (min, max) = (<$T>::min_value(), <$T>::max_value());
+                for n in 1..=bits {
+                    for &x in &[max, max - 1, max / 3, min, min + 1] {
+                        if n % 2 == 1 || !x.to_string().starts_with('-') {
+                            assert_eq!(generic_nth_root(x, n), x.nth_root(n), "{}√{}", n, x);
+                        }
+                    }
+                }
+            })*};
+        }
+        check!(i8 i16 i32 i64 i128 isize);
+        check!(u8 u16 u32 u64 u128 usize);
+    }
+
+    #[test]
+    fn exhaustive_16() {
+        for x in 0..=u16::max_value() {
+            let wide = u64::from(x);
+            assert_eq!(generic_sqrt(wide), wide.sqrt());
+            for n in 3..20 {
+                assert_eq!(generic_nth_root(wide, n), wide.nth_root(n));
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn zeroth_root() {
+        generic_nth_root(123u64, 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn sqrt_neg() {
+        generic_sqrt(-1i64);
+    }
+
+    #[test]
+    #[should_panic]
+    fn even_root_neg() {
+        generic_nth_root(-1i64, 4);
+    }
+}
+
+mod scaled {
+    use num_integer::{nth_root_scaled, sqrt_scaled, Roots};
+
+    #[test]
+    fn exhaustive_16() {
+        for x in 0..=u16::max_value() {
+            for n in 1..8 {
+                for f in (0..24).step_by(5).take_while(|f| n * f <= 112) {
+                    // The radicand fits in 128 bits, so this is a plain root.
+                    let r = (u128::from(x) << (n * f)).nth_root(n);
+                    let expected = if r <= u128::from(u16::max_value()) {
+                        Some(r as u16)
+                    } else {
+                        None
+                    };
+                    assert_eq!(x.nth_root_scaled(n, f), expected, "{} {} {}", x, n, f);
+                    if x <= i16::max_value() as u16 {
+                        let expected = expected.and_then(|r| {
+                            if r <= i16::max_value() as u16 {
+                                Some(r as i16)
+                            } else {
+                                None
+                            }
+                        });
+                        assert_eq!((x as i16).nth_root_scaled(n, f), expected);
+                    }
+                    if n % 2 == 1 && x <= 1 << 15 {
+                        // Negative roots have room for one more magnitude.
+                        let neg = (x as i16).wrapping_neg();
+                        let expected = if r <= 1 << 15 {
+                            Some((r as i16).wrapping_neg())
+                        } else {
+                            None
+                        };
+                        assert_eq!(neg.nth_root_scaled(n, f), expected);
+                    }
+                }
+            }
+        }
+        assert_eq!(i16::min_value().nth_root_scaled(15, 0), Some(-2));
+        assert_eq!(i16::min_value().nth_root_scaled(15, 1), Some(-4));
+        assert_eq!(
+            i16::min_value().nth_root_scaled(1, 0),
+            Some(i16::min_value())
+        );
+        assert_eq!(i16::min_value().nth_root_scaled(1, 1), None);
+    }
+
+    #[test]
+    fn wide() {
+        // Radicands of more than 128 bits, checked with arbitrary precision.
+        let cases = [
+            (u128::max_value(), 2, 64, Some(u128::max_value())),
+            (
+                u128::max_value(),
+                3,
+                42,
+                Some(30_704_801_884_924_481_767_496_089),
+            ),
+            (
+                3,
+                2,
+                127,
+                Some(294_693_174_213_430_241_384_087_455_685_767_077_316),
+            ),
+            ((1 << 127) + 12345, 5, 25, Some(1_485_633_833_817_307)),
+            (10u128.pow(30), 7, 30, Some(20_730_709_010_037)),
+            (2, 5, 52, Some(5_173_277_483_525_748)),
+            (2, 100, 3, Some(8)),
+            (
+                3,
+                63,
+                127,
+                Some(173_134_175_293_720_965_894_539_781_836_766_290_409),
+            ),
+            (5, 20, 100, Some(1_373_877_675_470_208_470_235_565_550_048)),
+            (
+                12_345_678_901_234_567_890_123,
+                3,
+                60,
+                Some(26_646_370_708_742_706_715_402_391),
+            ),
+            (
+                (1 << 100) - 1,
+                2,
+                78,
+                Some(340_282_366_920_938_463_463_374_607_431_633_993_727),
+            ),
+            (2, 11, 23, Some(8_934_212)),
+        ];
+        for &(x, n, f, expected) in &cases {
+            assert_eq!(nth_root_scaled(x, n, f), expected, "{} {} {}", x, n, f);
+        }
+
+        assert_eq!(sqrt_scaled(u128::max_value(), 65), None);
+        assert_eq!(sqrt_scaled(1u128, 127), Some(1 << 127));
+        assert_eq!(sqrt_scaled(1u128, 128), None);
+        assert_eq!(sqrt_scaled(u64::max_value(), 32), Some(u64::max_value()));
+        assert_eq!(sqrt_scaled(2u64, 63), Some(13_043_817_825_332_782_212));
+        assert_eq!(nth_root_scaled(7u64, 1, 62), None);
+        assert_eq!(nth_root_scaled(7u64, 1, 61), Some(7 << 61));
+        assert_eq!(nth_root_scaled(0u32, 3, u32::max_value()), Some(0));
+        assert_eq!(nth_root_scaled(1u32, 3, u32::max_value()), None);
+        assert_eq!(2u32.nth_root_scaled(100, 31), Some(2_162_420_578));
+        assert_eq!(2u32.nth_root_scaled(100, 32), None);
+
+        // Scales too large for the radicand's bit length to fit in a `u32`.
+        assert_eq!(3u8.sqrt_scaled(2_147_483_647), None);
+        assert_eq!(0u8.sqrt_scaled(2_147_483_647), Some(0));
+        assert_eq!(1u32.nth_root_scaled(1, u32::max_value()), None);
+        assert_eq!(u128::max_value().nth_root_scaled(1, u32::max_value()), None);
+        assert_eq!((-1i64).nth_root_scaled(3, 1_431_655_765), None);
+        assert_eq!(i128::max_value().nth_root_scaled(2, 2_147_483_647), None);
+
+        for k in 1..100u128 {
+            for n in 2..6 {
+                // Exact powers scale exactly.
+                let x = k.pow(n);
+                let f = 120 / n;
+                assert_eq!(nth_root_scaled(x, n, f), Some(k << f));
+                assert_eq!(nth_root_scaled(x as i128, n, f), Some((k << f) as i128));
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn zeroth_root() {
+        nth_root_scaled(5u64, 0, 10);
+    }
+
+    #[test]
+    #[should_panic]
+    fn sqrt_neg() {
+        sqrt_scaled(-5i64, 10);
+    }
+}
+
+mod hypot {
+    use num_integer::{hypot, norm, Roots};
+
+    fn isqrt(x: u64) -> u64 {
+        let mut r = (x as f64).sqrt() as u64;
+        while r * r > x {
+            r -= 1;
+        }
+        while (r + 1) * (r + 1) <= x {
+            r += 1;
+        }
+        r
+    }
+
+    #[test]
+    fn exhaustive_8() {
+        for a in 0..256u64 {
+            for b in 0..256u64 {
+                let r = isqrt(a * a + b * b);
+                if r <= 255 {
+                    assert_eq!(hypot(a as u8, b as u8) as u64, r);
+                }
+            }
+        }
+        for a in -128..128i64 {
+            for b in -128..128i64 {
+                let r = isqrt((a * a + b * b) as u64);
+                if r <= 127 {
+                    assert_eq!(hypot(a as i8, b as i8) as u64, r);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn norms() {
+        assert_eq!(u32::norm(&[]), 0);
+        assert_eq!(norm(&[-7i16]), 7);
+        assert_eq!(norm(&[1u64, 2, 2, 4]), 5);
+        assert_eq!(norm(&[u8::max_value(); 1]), u8::max_value());
+        assert_eq!(norm(&[i64::min_value() + 1, 0]), i64::max_value());
+        assert_eq!(norm(&[1u128 << 126; 4]), 1 << 127);
+        assert_eq!(norm(&[u128::max_value(), 1, 1]), u128::max_value());
+    }
+
+    #[test]
+    fn wide() {
+        let (a, b) = (3u128 << 100, 4u128 << 100);
+        assert_eq!(hypot(a, b), 5 << 100);
+        assert_eq!(hypot(a as i128, -(b as i128)), 5 << 100);
+        assert_eq!(hypot(u128::max_value(), 1), u128::max_value());
+
+        // Exact for a = 2ᵏ + 1 and b = 2ᵏ - 1, where a² + b² = 2·(4ᵏ + 1).
+        for k in 2..63u32 {
+            let (a, b) = ((1u64 << k) + 1, (1u64 << k) - 1);
+            let r = hypot(a, b);
+            let sq = u128::from(a) * u128::from(a) + u128::from(b) * u128::from(b);
+            assert!(u128::from(r) * u128::from(r) <= sq);
+            assert!(u128::from(r + 1) * u128::from(r + 1) > sq);
+            assert_eq!(u128::from(r), sq.sqrt());
+        }
+
+        for k in 1..63 {
+            let (a, b) = (u64::max_value() >> k, u64::max_value() >> (k + 1));
+            let sq = u128::from(a) * u128::from(a) + u128::from(b) * u128::from(b);
+            assert_eq!(u128::from(hypot(a, b)), sq.sqrt());
+            assert_eq!(a.hypot(&b), b.hypot(&a));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn overflow() {
+        hypot(u128::max_value(), u128::max_value());
+    }
+
+    #[test]
+    #[should_panic]
+    fn min_value() {
+        hypot(i8::min_value(), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn min_value_128() {
+        i128::norm(&[i128::min_value()]);
+    }
+}
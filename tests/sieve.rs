@@ -0,0 +1,68 @@
+#![cfg(feature = "alloc")]
+
+use num_integer::{factorize, is_prime, mobius, Integer, SpfSieve};
+
+#[test]
+fn small() {
+    let n = 10_000;
+    let sieve = SpfSieve::new(n);
+    assert_eq!(sieve.limit(), n);
+
+    let primes: Vec<u32> = (0..=n).filter(|&m| is_prime(m.into())).collect();
+    assert_eq!(sieve.primes(), &primes[..]);
+
+    assert_eq!(sieve.smallest_prime_factor(0), None);
+    assert_eq!(sieve.smallest_prime_factor(1), None);
+    assert_eq!(sieve.totient(0), 0);
+    assert_eq!(sieve.mobius(0), 0);
+
+    for m in 1..=n {
+        assert_eq!(sieve.is_prime(m), is_prime(m.into()), "is_prime({})", m);
+
+        let expected: Vec<(u32, u32)> = factorize(m.into())
+            .iter()
+            .map(|&(p, e)| (p as u32, e))
+            .collect();
+        let actual: Vec<(u32, u32)> = sieve.factors(m).collect();
+        assert_eq!(actual, expected, "factors({})", m);
+        assert_eq!(
+            sieve.smallest_prime_factor(m),
+            expected.first().map(|&(p, _)| p)
+        );
+
+        if m <= 2_000 {
+            let phi = (1..=m).filter(|k| k.gcd(&m) == 1).count();
+            assert_eq!(sieve.totient(m), phi as u32, "φ({})", m);
+        }
+        assert_eq!(sieve.mobius(m), mobius(m), "μ({})", m);
+    }
+}
+
+#[test]
+fn tiny() {
+    for n in 0..4 {
+        let sieve = SpfSieve::new(n);
+        assert_eq!(sieve.limit(), n);
+        assert_eq!(sieve.primes().len(), [0, 0, 1, 2][n as usize]);
+    }
+}
+
+#[test]
+fn million() {
+    let sieve = SpfSieve::new(1_000_000);
+    assert_eq!(sieve.primes().len(), 78_498);
+    assert_eq!(sieve.totient(1_000_000), 400_000);
+    assert_eq!(sieve.factors(999_999).count(), 5); // 3³·7·11·13·37
+}
+
+#[test]
+#[should_panic]
+fn out_of_range() {
+    SpfSieve::new(100).is_prime(101);
+}
+
+#[test]
+#[should_panic]
+fn factors_zero() {
+    SpfSieve::new(100).factors(0);
+}